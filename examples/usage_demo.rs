@@ -33,7 +33,7 @@ async fn example_with_f32() -> Result<(), ()> {
         .map_err(|_| ())?;
     
     // 创建MPU6050实例（默认使用f32）
-    // let mut mpu: Mpu6050<_, _, f32> = Mpu6050::new(spi, cs, config);
+    // let mut mpu: Mpu6050<_, f32> = Mpu6050::new(spi, cs, config);
     
     // 初始化设备
     // mpu.init_with_config().await.map_err(|_| ())?;
@@ -64,7 +64,7 @@ async fn example_with_fixed_point() -> Result<(), ()> {
         .map_err(|_| ())?;
     
     // 创建使用定点数的MPU6050实例
-    // let mut mpu: Mpu6050<_, _, FixedI16F16> = Mpu6050::new(spi, cs, config);
+    // let mut mpu: Mpu6050<_, FixedI16F16> = Mpu6050::new(spi, cs, config);
     
     // 初始化和校准
     // mpu.init_with_config().await.map_err(|_| ())?;
@@ -98,7 +98,7 @@ async fn example_advanced_config() -> Result<(), ()> {
         .build()
         .map_err(|_| ())?;
     
-    // let mut mpu: Mpu6050<_, _, f32> = Mpu6050::new(spi, cs, config);
+    // let mut mpu: Mpu6050<_, f32> = Mpu6050::new(spi, cs, config);
     
     // 初始化
     // mpu.init_with_config().await.map_err(|_| ())?;
@@ -131,14 +131,14 @@ async fn example_error_handling() -> Result<(), mpu6050::Mpu6050Error<()>> {
         .sample_rate(100) // 有效的采样率
         .build()?; // 使用?操作符处理错误
     
-    // let mut mpu: Mpu6050<_, _, f32> = Mpu6050::new(spi, cs, config);
+    // let mut mpu: Mpu6050<_, f32> = Mpu6050::new(spi, cs, config);
     
     // 使用统一的错误类型
     // match mpu.init_with_config().await {
     //     Ok(_) => {
     //         // 初始化成功
     //     }
-    //     Err(mpu6050::Mpu6050Error::Spi(_)) => {
+    //     Err(mpu6050::Mpu6050Error::Bus(_)) => {
     //         // SPI通信错误
     //     }
     //     Err(mpu6050::Mpu6050Error::DeviceNotFound) => {
@@ -228,6 +228,6 @@ fn example_backward_compatibility() {
         .unwrap();
     
     // 类型别名提供向后兼容性：
-    // type OldMpu6050<SPI, CS> = mpu6050::Mpu6050F32<SPI, CS>;
+    // type OldMpu6050<DI> = mpu6050::Mpu6050F32<DI>;
     // type OldSensorData = mpu6050::SensorDataF32;
 }
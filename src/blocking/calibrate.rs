@@ -0,0 +1,281 @@
+use super::{Interface, Mpu6050};
+use crate::calibration::{
+    AccelCalibration, CalibrationProfile, GyroCalibrationConfig, GyroCalibrationReport,
+    GyroStillness, GyroTempModel, RunningStats, SixPosition, SixPositionCalibrator, StillnessStep,
+    ThermalCalibrator,
+};
+use crate::driver::calibrate::{GyroTempSampler, RawSum, accel_offset_from_stats};
+use crate::error::{Mpu6050Error, Result};
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: crate::numeric::NumericType,
+{
+    /// 校准传感器
+    pub fn calibrate_sensors(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        self.calibrate_accel(cycle)?;
+        self.calibrate_gyro(cycle)
+    }
+
+    /// 校准加速度计（软件偏移）
+    ///
+    /// 要求、判定与对已有标定的处理同异步版本 [`crate::Mpu6050::calibrate_accel`]
+    pub fn calibrate_accel(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        if cycle == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut stats = RunningStats::new();
+        for _ in 0..cycle {
+            let (x, y, z) = self.read_accel_raw()?;
+            stats.push([x as f32, y as f32, z as f32]);
+            self.delay.delay_us(500);
+        }
+        let lsb_per_g = self.config.accel_scale.get_scale_factor();
+        let offset =
+            accel_offset_from_stats(&stats, lsb_per_g).ok_or(Mpu6050Error::CalibrationFailed)?;
+        self.accel_offset = offset;
+        self.accel_calibration = AccelCalibration::identity();
+        Ok(())
+    }
+
+    /// 校准陀螺仪（软件偏移）
+    ///
+    /// 新偏移给出完整零偏，已设置的温度模型会被清除（后执行的标定生效）。
+    /// `cycle` 为0时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub fn calibrate_gyro(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        if cycle == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut sum = RawSum::default();
+        for _ in 0..cycle {
+            sum.push(self.read_gyro_raw()?);
+            self.delay.delay_us(500);
+        }
+        self.store_gyro_offset(sum.average());
+        Ok(())
+    }
+
+    /// 带静止检测的陀螺仪校准，判定规则同异步版本 [`crate::Mpu6050::calibrate_gyro_still`]
+    ///
+    /// 阻塞版本没有时钟源，`timeout_ms` 按采样间累计延时估算（不含总线传输时间）
+    pub fn calibrate_gyro_still(
+        &mut self,
+        config: GyroCalibrationConfig,
+    ) -> Result<GyroCalibrationReport, DI::Error> {
+        if config.samples == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let lsb_per_rad = self.config.gyro_scale.get_scale_factor() * crate::RAD2DEG;
+        let mut still = GyroStillness::new(config, lsb_per_rad);
+        let mut elapsed_us: u64 = 0;
+        loop {
+            let raw = self.read_gyro_raw()?;
+            match still.push(raw) {
+                StillnessStep::Collecting => {}
+                StillnessStep::Done(report) => {
+                    self.store_gyro_offset(report.offset);
+                    return Ok(report);
+                }
+                StillnessStep::Moved => {
+                    let Some(timeout_ms) = config.timeout_ms else {
+                        return Err(Mpu6050Error::CalibrationFailed);
+                    };
+                    if elapsed_us > timeout_ms as u64 * 1000 {
+                        return Err(Mpu6050Error::Timeout);
+                    }
+                    still.restart();
+                }
+            }
+            self.delay.delay_us(500);
+            elapsed_us += 500;
+        }
+    }
+
+    /// 设置加速度计标定（如六面法结果），同时清零软件加速度偏移
+    pub fn set_accel_calibration(&mut self, calibration: AccelCalibration) {
+        self.accel_calibration = calibration;
+        self.accel_offset = (0, 0, 0);
+    }
+
+    /// 当前加速度计标定
+    pub fn accel_calibration(&self) -> AccelCalibration {
+        self.accel_calibration
+    }
+
+    /// 设置陀螺仪零偏温度模型；设置为 `Some` 时模型给出完整零偏，软件陀螺仪偏移清零
+    ///
+    /// 模型在 `read_gyro`、`read_all` 及基于它们的姿态更新中自动生效
+    pub fn set_gyro_temp_model(&mut self, model: Option<GyroTempModel>) {
+        if model.is_some() {
+            self.gyro_offset = (0, 0, 0);
+        }
+        self.gyro_temp_model = model;
+    }
+
+    /// 当前陀螺仪零偏温度模型
+    pub fn gyro_temp_model(&self) -> Option<GyroTempModel> {
+        self.gyro_temp_model
+    }
+
+    /// 写入软件陀螺仪偏移；偏移由未补偿数据算出，温度模型随之失效
+    fn store_gyro_offset(&mut self, offset: (i16, i16, i16)) {
+        self.gyro_offset = offset;
+        self.gyro_temp_model = None;
+    }
+
+    /// 温度补偿标定：静置采集当前温度下的陀螺仪零偏并记录到 `calibrator`
+    ///
+    /// 在不同温度（如升温过程中）多次调用后用 [`ThermalCalibrator::fit`] 拟合，
+    /// 再通过 [`set_gyro_temp_model`](Self::set_gyro_temp_model) 应用。
+    /// 返回 `(温度 °C, 三轴零偏 rad/s)`。
+    ///
+    /// 采集 `config.samples` 个样本，任一轴标准差超过 `config.max_std_dev` 视为晃动；
+    /// 不做重新采集，`timeout_ms` 不使用。
+    /// 样本数为0、采集期间设备晃动或记录已满时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub fn record_gyro_temp_point(
+        &mut self,
+        calibrator: &mut ThermalCalibrator,
+        config: &GyroCalibrationConfig,
+    ) -> Result<(f32, [f32; 3]), DI::Error> {
+        if config.samples == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let lsb_per_rad = self.config.gyro_scale.get_scale_factor() * crate::RAD2DEG;
+        let mut sampler = GyroTempSampler::new(lsb_per_rad);
+        for _ in 0..config.samples {
+            sampler.push(&self.read_all_raw()?, self.chip);
+            self.delay.delay_us(500);
+        }
+        let (temp, bias) = sampler
+            .finish(config.max_std_dev)
+            .ok_or(Mpu6050Error::CalibrationFailed)?;
+        if !calibrator.add_point(temp, bias) {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        Ok((temp, bias))
+    }
+
+    /// 导出当前标定（软件偏移、加速度计标定、温度模型及当前量程）为档案
+    pub fn calibration_profile(&self) -> CalibrationProfile {
+        CalibrationProfile {
+            accel_scale: self.config.accel_scale,
+            gyro_scale: self.config.gyro_scale,
+            accel_offset: self.accel_offset,
+            gyro_offset: self.gyro_offset,
+            accel_calibration: self.accel_calibration,
+            gyro_temp_model: self.gyro_temp_model,
+        }
+    }
+
+    /// 应用标定档案，软件偏移按当前配置量程换算
+    pub fn apply_calibration_profile(&mut self, profile: &CalibrationProfile) {
+        (self.accel_offset, self.gyro_offset) =
+            profile.offsets_for(self.config.accel_scale, self.config.gyro_scale);
+        self.accel_calibration = profile.accel_calibration;
+        self.gyro_temp_model = profile.gyro_temp_model;
+    }
+
+    /// 六面法标定：采集一个姿态的平均读数并记录到 `calibrator`
+    ///
+    /// 读数未扣除软件偏移与现有标定。`samples` 为0或读数与 `position` 不符
+    /// （放置方向错误）时返回 [`Mpu6050Error::CalibrationFailed`]。
+    /// 六个姿态采集完成后用 [`SixPositionCalibrator::solve`] 解算，
+    /// 再通过 [`set_accel_calibration`](Self::set_accel_calibration) 应用
+    pub fn collect_accel_position(
+        &mut self,
+        calibrator: &mut SixPositionCalibrator,
+        position: SixPosition,
+        samples: u16,
+    ) -> Result<[f32; 3], DI::Error> {
+        if samples == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut sum = RawSum::default();
+        for _ in 0..samples {
+            sum.push(self.read_accel_raw()?);
+            self.delay.delay_us(500);
+        }
+        let mean = sum.accel_mean(self.config.accel_scale.get_scale_factor());
+        if !calibrator.record(position, mean) {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        Ok(mean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Mpu6050Config;
+    use crate::driver::convert::GRAVITY;
+    use crate::interface::mock::{MockInterface, NoDelay};
+    use crate::register::*;
+
+    #[test]
+    fn test_calibrate_accel_replaces_calibration() {
+        let mut iface = MockInterface::new();
+        // z 轴朝上，静置读数 1g + 100 LSB
+        iface.regs[ACCEL_ZOUT_H as usize..ACCEL_ZOUT_H as usize + 2]
+            .copy_from_slice(&16484i16.to_be_bytes());
+        let mut mpu: Mpu6050<_, _, f32> =
+            Mpu6050::with_interface(iface, NoDelay, Mpu6050Config::default());
+        mpu.set_accel_calibration(AccelCalibration {
+            bias: [0.0, 0.0, 0.3],
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.98]],
+        });
+
+        mpu.calibrate_accel(8).unwrap();
+        assert_eq!(mpu.accel_offset, (0, 0, 100));
+        assert_eq!(mpu.accel_calibration(), AccelCalibration::identity());
+        let (ax, ay, az) = mpu.read_accel().unwrap();
+        assert!(ax.abs() < 1e-6 && ay.abs() < 1e-6);
+        assert!((az - GRAVITY).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_calibrate_gyro_replaces_temp_model() {
+        let mut iface = MockInterface::new();
+        // 温度原始值 340 → 37.53°C；x 轴静置零偏 131 LSB = 1°/s
+        iface.regs[TEMP_OUT_H as usize..TEMP_OUT_H as usize + 2].copy_from_slice(&[0x01, 0x54]);
+        iface.regs[GYRO_XOUT_H as usize..GYRO_XOUT_H as usize + 2].copy_from_slice(&[0x00, 0x83]);
+        let mut mpu: Mpu6050<_, _, f32> =
+            Mpu6050::with_interface(iface, NoDelay, Mpu6050Config::default());
+        mpu.set_gyro_temp_model(Some(GyroTempModel {
+            reference_temp: 36.53,
+            coeffs: [[0.5 * crate::DEG2RAD, 0.0, 0.0], [0.0; 3], [0.0; 3]],
+        }));
+
+        mpu.calibrate_gyro(8).unwrap();
+        assert_eq!(mpu.gyro_offset, (131, 0, 0));
+        assert!(mpu.gyro_temp_model().is_none());
+        let (gx, gy, gz) = mpu.read_gyro().unwrap();
+        assert!(gx.abs() < 1e-6 && gy.abs() < 1e-6 && gz.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_record_gyro_temp_point_uses_config() {
+        let mut iface = MockInterface::new();
+        iface.regs[TEMP_OUT_H as usize..TEMP_OUT_H as usize + 2].copy_from_slice(&[0x01, 0x54]);
+        iface.regs[GYRO_XOUT_H as usize..GYRO_XOUT_H as usize + 2].copy_from_slice(&[0x00, 0x83]);
+        let mut mpu: Mpu6050<_, _, f32> =
+            Mpu6050::with_interface(iface, NoDelay, Mpu6050Config::default());
+        let mut thermal = ThermalCalibrator::new();
+
+        let empty = GyroCalibrationConfig {
+            samples: 0,
+            ..GyroCalibrationConfig::default()
+        };
+        assert!(mpu.record_gyro_temp_point(&mut thermal, &empty).is_err());
+
+        let config = GyroCalibrationConfig {
+            samples: 4,
+            ..GyroCalibrationConfig::default()
+        };
+        let (temp, bias) = mpu.record_gyro_temp_point(&mut thermal, &config).unwrap();
+        assert!((temp - 37.53).abs() < 1e-2);
+        assert!((bias[0] - crate::DEG2RAD).abs() < 1e-5);
+    }
+}
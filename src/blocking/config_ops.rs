@@ -0,0 +1,31 @@
+use super::{Interface, Mpu6050};
+use crate::config::*;
+use crate::error::Result;
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: crate::numeric::NumericType,
+{
+    /// 设置加速度计量程
+    pub fn set_accel_scale(&mut self, scale: AccelScale) -> Result<(), DI::Error> {
+        self.config.accel_scale = scale;
+        self.write_register(ACCEL_CONFIG, scale as u8)
+    }
+
+    /// 设置陀螺仪量程
+    pub fn set_gyro_scale(&mut self, scale: GyroScale) -> Result<(), DI::Error> {
+        self.config.gyro_scale = scale;
+        self.write_register(GYRO_CONFIG, scale as u8)
+    }
+
+    /// 设置时钟源
+    pub fn set_clock_source(&mut self, clock_source: ClockSource) -> Result<(), DI::Error> {
+        self.config.clock_source = clock_source;
+        let current = self.read_register(PWR_MGMT_1)?;
+        self.write_register(PWR_MGMT_1, (current & 0xF8) | (clock_source as u8))
+    }
+}
@@ -0,0 +1,183 @@
+use super::{Interface, Mpu6050};
+use crate::SensorData;
+use crate::config::FifoChannels;
+use crate::driver::convert::{mount_raw, sensor_data_from_raw};
+use crate::driver::fifo::{FIFO_CHUNK_LEN, FifoRead, FifoStatus};
+use crate::error::Result;
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: crate::numeric::NumericType,
+{
+    /// 启用FIFO，写入 `config.fifo_channels` 选择的通道
+    ///
+    /// USER_CTRL 按读-改-写更新，保留 I2C_MST_EN、I2C_IF_DIS 等其他位
+    pub fn enable_fifo(&mut self) -> Result<(), DI::Error> {
+        self.fifo_pending.clear();
+        let current = self.read_register(USER_CTRL)?;
+        self.write_register(USER_CTRL, current | USER_CTRL_FIFO_EN)?;
+        self.write_register(FIFO_EN, self.config.fifo_channels.bits())
+    }
+
+    /// 清空并重新启用FIFO，之后的数据从帧边界开始
+    pub fn reset_fifo(&mut self) -> Result<(), DI::Error> {
+        self.write_register(FIFO_EN, 0x00)?;
+        // FIFO_RESET 需在 FIFO 使能位为0时写入
+        let current = self.read_register(USER_CTRL)?;
+        self.write_register(
+            USER_CTRL,
+            (current & !USER_CTRL_FIFO_EN) | USER_CTRL_FIFO_RESET,
+        )?;
+        self.enable_fifo()
+    }
+
+    /// FIFO 溢出统计
+    pub fn fifo_status(&self) -> FifoStatus {
+        self.fifo_status
+    }
+
+    /// 清零 FIFO 溢出统计
+    pub fn clear_fifo_status(&mut self) {
+        self.fifo_status = FifoStatus::default();
+    }
+
+    /// 禁用FIFO
+    pub fn disable_fifo(&mut self) -> Result<(), DI::Error> {
+        self.write_register(FIFO_EN, 0x00)?;
+        let current = self.read_register(USER_CTRL)?;
+        self.write_register(USER_CTRL, current & !USER_CTRL_FIFO_EN)?;
+        self.fifo_pending.clear();
+        Ok(())
+    }
+
+    /// 读取FIFO计数
+    pub fn read_fifo_count(&mut self) -> Result<u16, DI::Error> {
+        let mut buf = [0u8; 2];
+        self.read_registers(FIFO_COUNTH, &mut buf)?;
+        Ok(u16::from_be_bytes(buf))
+    }
+
+    /// 读取FIFO数据，填满 `data`
+    pub fn read_fifo_data(&mut self, data: &mut [u8]) -> Result<(), DI::Error> {
+        self.read_registers(FIFO_R_W, data)
+    }
+
+    /// 读取并解码 FIFO 中的完整帧，返回写入 `out` 的帧数
+    ///
+    /// 行为同异步版本 [`crate::Mpu6050::read_fifo_frames`]，包括溢出检测与恢复
+    pub fn read_fifo_frames(&mut self, out: &mut [SensorData<T>]) -> Result<usize, DI::Error> {
+        let channels = self.config.fifo_channels;
+        let frame_len = channels.frame_len();
+        if out.is_empty() || frame_len == 0 {
+            return Ok(0);
+        }
+        let int_status = self.read_register(INT_STATUS)?;
+        let available = self.read_fifo_count()? as usize;
+        let mut remaining = match self.fifo_pending.plan(
+            &mut self.fifo_status,
+            int_status,
+            available,
+            self.chip.fifo_size(),
+            frame_len,
+            out.len(),
+        ) {
+            FifoRead::Overflow => {
+                self.reset_fifo()?;
+                return Ok(0);
+            }
+            FifoRead::Bytes(0) => return Ok(0),
+            FifoRead::Bytes(n) => n,
+        };
+        let fallback_temp = if !channels.contains(FifoChannels::TEMP) {
+            let mut buf = [0u8; 2];
+            self.read_registers(TEMP_OUT_H, &mut buf)?;
+            i16::from_be_bytes(buf)
+        } else {
+            0
+        };
+
+        let mut count = 0;
+        let mut chunk = [0u8; FIFO_CHUNK_LEN];
+        while remaining > 0 {
+            let len = remaining.min(FIFO_CHUNK_LEN);
+            self.read_registers(FIFO_R_W, &mut chunk[..len])?;
+            remaining -= len;
+            count += self.fifo_pending.drain(
+                &chunk[..len],
+                channels,
+                fallback_temp,
+                &mut out[count..],
+                |raw| {
+                    sensor_data_from_raw(
+                        &mount_raw(raw, &self.config.mounting),
+                        self.accel_offset,
+                        self.gyro_offset,
+                        &self.accel_calibration,
+                        self.gyro_temp_model.as_ref(),
+                        &self.config,
+                        self.chip,
+                    )
+                },
+            );
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Mpu6050Config;
+    use crate::interface::mock::{MockInterface, NoDelay};
+
+    /// USER_CTRL 的 I2C_MST_EN | I2C_IF_DIS
+    const I2C_BITS: u8 = 0x30;
+
+    #[test]
+    fn test_overflow_mid_frame_resyncs_on_frame_boundary() {
+        let mut iface = MockInterface::new();
+        iface.regs[USER_CTRL as usize] = I2C_BITS;
+        let mut mpu: Mpu6050<_, _, f32> =
+            Mpu6050::with_interface(iface, NoDelay, Mpu6050Config::default());
+        mpu.enable_fifo().unwrap();
+        assert_eq!(
+            mpu.interface.regs[USER_CTRL as usize],
+            I2C_BITS | USER_CTRL_FIFO_EN
+        );
+
+        // 加速度 +1g z，陀螺仪 x 131 LSB；先到达半帧
+        let frame = [0, 0, 0, 0, 0x40, 0, 0, 0x83, 0, 0, 0, 0];
+        mpu.interface.push_fifo(&frame[..5]);
+        let mut out = [SensorData::default(); 4];
+        assert_eq!(mpu.read_fifo_frames(&mut out).unwrap(), 0);
+        assert_eq!(mpu.fifo_pending.len(), 5);
+
+        // 帧中途溢出：剩余字节已错位，缓存与 FIFO 一并丢弃
+        mpu.interface.push_fifo(&frame[5..9]);
+        mpu.interface.regs[INT_STATUS as usize] = INT_STATUS_FIFO_OFLOW;
+        assert_eq!(mpu.read_fifo_frames(&mut out).unwrap(), 0);
+        assert_eq!(mpu.fifo_pending.len(), 0);
+        assert_eq!(mpu.fifo_status().dropped_frames, 1);
+        assert_eq!(mpu.interface.fifo_len, 0);
+        assert_eq!(
+            mpu.interface.regs[USER_CTRL as usize],
+            I2C_BITS | USER_CTRL_FIFO_EN
+        );
+
+        // 复位后新数据从帧边界开始
+        mpu.interface.regs[INT_STATUS as usize] = 0;
+        mpu.interface.push_fifo(&frame);
+        mpu.interface.push_fifo(&frame[..3]);
+        assert_eq!(mpu.read_fifo_frames(&mut out).unwrap(), 1);
+        assert!((out[0].accel.2 - 9.81).abs() < 1e-3);
+        assert!((out[0].gyro.0 - crate::DEG2RAD).abs() < 1e-4);
+        assert_eq!(mpu.fifo_pending.len(), 3);
+
+        mpu.disable_fifo().unwrap();
+        assert_eq!(mpu.interface.regs[USER_CTRL as usize], I2C_BITS);
+    }
+}
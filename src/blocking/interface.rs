@@ -0,0 +1,89 @@
+//! 阻塞总线接口
+
+use crate::error::Result;
+use crate::interface::I2cAddress;
+
+use embedded_hal::i2c::I2c;
+use embedded_hal::spi::{Operation, SpiDevice};
+
+/// SPI 读标志位
+const SPI_READ: u8 = 0x80;
+
+/// 阻塞寄存器访问接口，语义与 [`crate::interface::Interface`] 相同
+pub trait Interface {
+    /// 总线错误类型
+    type Error;
+
+    /// 从 `reg` 开始连续读取 `buf.len()` 个字节（突发读取）
+    fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// 写入单个寄存器
+    fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error>;
+
+    /// 读取单个寄存器
+    fn read_register(&mut self, reg: u8) -> Result<u8, Self::Error> {
+        let mut buf = [0u8; 1];
+        self.read_registers(reg, &mut buf)?;
+        Ok(buf[0])
+    }
+}
+
+/// 基于 `SpiDevice` 的阻塞接口，片选由 `SpiDevice` 实现管理
+pub struct SpiInterface<SPI> {
+    spi: SPI,
+}
+
+impl<SPI> SpiInterface<SPI> {
+    /// 创建SPI接口
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+}
+
+impl<SPI> Interface for SpiInterface<SPI>
+where
+    SPI: SpiDevice<u8>,
+{
+    type Error = SPI::Error;
+
+    fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        Ok(self
+            .spi
+            .transaction(&mut [Operation::Write(&[reg | SPI_READ]), Operation::Read(buf)])?)
+    }
+
+    fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+        Ok(self.spi.write(&[reg & !SPI_READ, val])?)
+    }
+}
+
+/// 基于 `I2c` 的阻塞接口
+pub struct I2cInterface<I2C> {
+    i2c: I2C,
+    address: u8,
+}
+
+impl<I2C> I2cInterface<I2C> {
+    /// 创建I2C接口
+    pub fn new(i2c: I2C, address: I2cAddress) -> Self {
+        Self {
+            i2c,
+            address: address as u8,
+        }
+    }
+}
+
+impl<I2C> Interface for I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    type Error = I2C::Error;
+
+    fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        Ok(self.i2c.write_read(self.address, &[reg], buf)?)
+    }
+
+    fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+        Ok(self.i2c.write(self.address, &[reg, val])?)
+    }
+}
@@ -0,0 +1,27 @@
+use super::{Interface, Mpu6050};
+use crate::config::*;
+use crate::error::Result;
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: crate::numeric::NumericType,
+{
+    /// 启用中断
+    pub fn enable_interrupts(&mut self) -> Result<(), DI::Error> {
+        self.write_register(INT_ENABLE, InterruptType::DataReady as u8)
+    }
+
+    /// 禁用中断
+    pub fn disable_interrupts(&mut self) -> Result<(), DI::Error> {
+        self.write_register(INT_ENABLE, 0x00)
+    }
+
+    /// 读取中断状态
+    pub fn read_interrupt_status(&mut self) -> Result<u8, DI::Error> {
+        self.read_register(INT_STATUS)
+    }
+}
@@ -0,0 +1,172 @@
+//! 阻塞（非async）版本驱动
+//!
+//! 适用于无法运行异步执行器的场景（bootloader、RTIC等）。
+//! 基于 `embedded-hal` 1.0 的 `SpiDevice`/`I2c` 与 `DelayNs`，
+//! 数据转换与姿态解算与异步版本共用同一实现。
+
+mod calibrate;
+mod config_ops;
+mod fifo;
+pub mod interface;
+mod interrupt;
+mod offset;
+mod power;
+mod read;
+mod self_test;
+mod timing;
+
+pub use interface::{I2cInterface, Interface, SpiInterface};
+
+use crate::config::Mpu6050Config;
+use crate::driver::ChipVariant;
+use crate::error::{ConfigValidation, Mpu6050Error, Result};
+use crate::interface::I2cAddress;
+use crate::numeric::NumericType;
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::I2c;
+use embedded_hal::spi::SpiDevice;
+
+/// MPU6050 阻塞版主结构体
+///
+/// `DI` 为阻塞总线接口，`D` 为延时提供者
+pub struct Mpu6050<DI, D, T: NumericType = f32> {
+    pub(crate) interface: DI,
+    pub(crate) delay: D,
+    pub(crate) config: Mpu6050Config,
+    pub(crate) chip: ChipVariant,
+    pub(crate) accel_offset: (i16, i16, i16),
+    pub(crate) gyro_offset: (i16, i16, i16),
+    pub(crate) accel_calibration: crate::calibration::AccelCalibration,
+    pub(crate) gyro_temp_model: Option<crate::calibration::GyroTempModel>,
+    pub(crate) last_update: Option<embassy_time::Instant>,
+    pub(crate) fifo_pending: crate::driver::FifoPending,
+    pub(crate) fifo_status: crate::driver::FifoStatus,
+    pub pitch: T,
+    pub roll: T,
+    pub yaw: T,
+}
+
+impl<SPI, D, T> Mpu6050<SpiInterface<SPI>, D, T>
+where
+    SPI: SpiDevice<u8>,
+    D: DelayNs,
+    T: NumericType,
+{
+    /// 创建基于SPI的MPU6050实例
+    pub fn new(spi: SPI, delay: D, config: Mpu6050Config) -> Self {
+        Self::with_interface(SpiInterface::new(spi), delay, config)
+    }
+}
+
+impl<I2C, D, T> Mpu6050<I2cInterface<I2C>, D, T>
+where
+    I2C: I2c,
+    D: DelayNs,
+    T: NumericType,
+{
+    /// 创建基于I2C的MPU6050实例
+    pub fn new_i2c(i2c: I2C, address: I2cAddress, delay: D, config: Mpu6050Config) -> Self {
+        Self::with_interface(I2cInterface::new(i2c, address), delay, config)
+    }
+}
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: NumericType,
+{
+    /// 使用任意阻塞总线接口创建MPU6050实例
+    pub fn with_interface(interface: DI, delay: D, config: Mpu6050Config) -> Self {
+        Self {
+            interface,
+            delay,
+            config,
+            chip: ChipVariant::default(),
+            accel_offset: (0, 0, 0),
+            gyro_offset: (0, 0, 0),
+            accel_calibration: crate::calibration::AccelCalibration::identity(),
+            gyro_temp_model: None,
+            last_update: None,
+            fifo_pending: crate::driver::FifoPending::default(),
+            fifo_status: crate::driver::FifoStatus::default(),
+            pitch: T::zero(),
+            roll: T::zero(),
+            yaw: T::zero(),
+        }
+    }
+
+    /// 释放并返回底层总线接口和延时提供者
+    pub fn release(self) -> (DI, D) {
+        (self.interface, self.delay)
+    }
+
+    /// 当前识别到的芯片型号（[`init`](Self::init) 之前为默认的 MPU6050）
+    pub fn chip_variant(&self) -> ChipVariant {
+        self.chip
+    }
+
+    /// 基本初始化
+    ///
+    /// 读取 WHO_AM_I 识别芯片型号，ID不匹配时返回 [`Mpu6050Error::DeviceNotFound`]
+    pub fn init(&mut self) -> Result<ChipVariant, DI::Error> {
+        let id = self.who_am_i()?;
+        self.chip = ChipVariant::from_who_am_i(id).ok_or(Mpu6050Error::DeviceNotFound)?;
+        // 唤醒设备
+        self.write_register(PWR_MGMT_1, 0x00)?;
+        self.delay.delay_ms(100);
+        Ok(self.chip)
+    }
+
+    /// 完整初始化和配置
+    ///
+    /// 配置无效时返回 [`Mpu6050Error::InvalidConfig`]
+    pub fn init_with_config(&mut self) -> Result<(), DI::Error> {
+        self.config
+            .validate()
+            .map_err(|_| Mpu6050Error::InvalidConfig)?;
+        self.init()?;
+
+        self.set_clock_source(self.config.clock_source)?;
+        let sample_rate_div = self.config.sample_rate_divider();
+        self.write_register(SMPLRT_DIV, sample_rate_div)?;
+        self.write_register(CONFIG, self.config.dlpf_config as u8)?;
+        if self.chip.is_mpu6500_family() {
+            // MPU6500系列的加速度计DLPF独立配置
+            self.write_register(ACCEL_CONFIG2, self.config.dlpf_config as u8)?;
+        }
+        self.set_gyro_scale(self.config.gyro_scale)?;
+        self.set_accel_scale(self.config.accel_scale)?;
+        if self.config.enable_interrupts {
+            self.enable_interrupts()?;
+        }
+        if self.config.enable_fifo {
+            self.enable_fifo()?;
+        }
+
+        Ok(())
+    }
+
+    /// 校准初始化（包含传感器校准）
+    pub fn calibrate_init(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        self.init_with_config()?;
+        self.calibrate_sensors(cycle)?;
+        Ok(())
+    }
+
+    /// 读取寄存器
+    pub fn read_register(&mut self, reg: u8) -> Result<u8, DI::Error> {
+        self.interface.read_register(reg)
+    }
+
+    /// 连续读取多个寄存器
+    pub fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), DI::Error> {
+        self.interface.read_registers(reg, buf)
+    }
+
+    /// 写入寄存器
+    pub fn write_register(&mut self, reg: u8, val: u8) -> Result<(), DI::Error> {
+        self.interface.write_register(reg, val)
+    }
+}
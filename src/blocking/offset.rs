@@ -0,0 +1,151 @@
+use super::{Interface, Mpu6050};
+use crate::calibration::AccelCalibration;
+use crate::driver::convert::decode_i16x3;
+use crate::driver::offset::{
+    GYRO_OFFSET_REGISTERS, accel_hardware_trim, gyro_hardware_trim, pack_accel_offset,
+};
+use crate::error::{Mpu6050Error, Result};
+use crate::numeric::NumericType;
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: NumericType,
+{
+    /// 读取加速度计片上偏移寄存器（±16g 量程单位，第0位为温度补偿位）
+    pub fn read_accel_offset_registers(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let regs = self.chip.accel_offset_registers();
+        let mut out = [0i16; 3];
+        for (reg, val) in regs.into_iter().zip(out.iter_mut()) {
+            let mut buf = [0u8; 2];
+            self.read_registers(reg, &mut buf)?;
+            *val = i16::from_be_bytes(buf);
+        }
+        Ok((out[0], out[1], out[2]))
+    }
+
+    /// 写入加速度计片上偏移寄存器
+    ///
+    /// 第0位为出厂温度补偿位，写入时保留芯片中的原值
+    pub fn write_accel_offset_registers(
+        &mut self,
+        offset: (i16, i16, i16),
+    ) -> Result<(), DI::Error> {
+        let current = self.read_accel_offset_registers()?;
+        let regs = self.chip.accel_offset_registers();
+        let pairs = [
+            (offset.0, current.0),
+            (offset.1, current.1),
+            (offset.2, current.2),
+        ];
+        for (reg, (new, old)) in regs.into_iter().zip(pairs) {
+            let [high, low] = pack_accel_offset(new, old).to_be_bytes();
+            self.write_register(reg, high)?;
+            self.write_register(reg + 1, low)?;
+        }
+        Ok(())
+    }
+
+    /// 读取陀螺仪片上偏移寄存器（XG_OFFS_USRH..ZG_OFFS_USRL，±1000°/s 量程单位）
+    pub fn read_gyro_offset_registers(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let mut buf = [0u8; 6];
+        self.read_registers(XG_OFFS_USRH, &mut buf)?;
+        Ok(decode_i16x3(&buf))
+    }
+
+    /// 写入陀螺仪片上偏移寄存器
+    pub fn write_gyro_offset_registers(
+        &mut self,
+        offset: (i16, i16, i16),
+    ) -> Result<(), DI::Error> {
+        for (reg, val) in GYRO_OFFSET_REGISTERS
+            .into_iter()
+            .zip([offset.0, offset.1, offset.2])
+        {
+            let [high, low] = val.to_be_bytes();
+            self.write_register(reg, high)?;
+            self.write_register(reg + 1, low)?;
+        }
+        Ok(())
+    }
+
+    /// 计算并写入片上偏移寄存器
+    ///
+    /// 要求与换算同异步版本 [`crate::Mpu6050::calibrate_hardware_offsets`]，
+    /// 完成后软件偏移、加速度计标定与陀螺仪温度模型一并重置
+    pub fn calibrate_hardware_offsets(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        if cycle == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut accel_sum = (0i32, 0i32, 0i32);
+        let mut gyro_sum = (0i32, 0i32, 0i32);
+        for _ in 0..cycle {
+            let raw = self.read_all_raw_chip()?;
+            accel_sum.0 += raw.accel.0 as i32;
+            accel_sum.1 += raw.accel.1 as i32;
+            accel_sum.2 += raw.accel.2 as i32;
+            gyro_sum.0 += raw.gyro.0 as i32;
+            gyro_sum.1 += raw.gyro.1 as i32;
+            gyro_sum.2 += raw.gyro.2 as i32;
+            self.delay.delay_us(500);
+        }
+
+        let current = self.read_accel_offset_registers()?;
+        let accel_trim = accel_hardware_trim(accel_sum, cycle, current, &self.config);
+        self.write_accel_offset_registers(accel_trim)?;
+
+        let current = self.read_gyro_offset_registers()?;
+        let gyro_trim = gyro_hardware_trim(gyro_sum, cycle, current, &self.config);
+        self.write_gyro_offset_registers(gyro_trim)?;
+
+        self.accel_offset = (0, 0, 0);
+        self.gyro_offset = (0, 0, 0);
+        self.accel_calibration = AccelCalibration::identity();
+        self.gyro_temp_model = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::GyroTempModel;
+    use crate::config::Mpu6050Config;
+    use crate::interface::mock::{MockInterface, NoDelay};
+
+    #[test]
+    fn test_calibrate_hardware_offsets() {
+        let mut iface = MockInterface::new();
+        // ±2g 下 x 轴偏差 800 LSB，z 轴 1g；陀螺仪 x 轴偏差 -131 LSB
+        iface.regs[ACCEL_XOUT_H as usize..ACCEL_XOUT_H as usize + 2]
+            .copy_from_slice(&800i16.to_be_bytes());
+        iface.regs[ACCEL_ZOUT_H as usize..ACCEL_ZOUT_H as usize + 2]
+            .copy_from_slice(&16384i16.to_be_bytes());
+        iface.regs[GYRO_XOUT_H as usize..GYRO_XOUT_H as usize + 2]
+            .copy_from_slice(&(-131i16).to_be_bytes());
+        iface.regs[XA_OFFS_USRL as usize] = 0x01;
+        let mut mpu: Mpu6050<_, _, f32> =
+            Mpu6050::with_interface(iface, NoDelay, Mpu6050Config::default());
+        mpu.accel_offset = (1, 2, 3);
+        mpu.accel_calibration = AccelCalibration {
+            bias: [0.1, 0.0, 0.0],
+            matrix: [[1.02, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        };
+        mpu.gyro_temp_model = Some(GyroTempModel {
+            reference_temp: 25.0,
+            coeffs: [[0.01, 0.0, 0.0], [0.0; 3], [0.0; 3]],
+        });
+
+        mpu.calibrate_hardware_offsets(4).unwrap();
+        // 温度补偿位保留
+        assert_eq!(mpu.read_accel_offset_registers().unwrap(), (-99, 0, 0));
+        assert_eq!(mpu.read_gyro_offset_registers().unwrap(), (33, 0, 0));
+        assert_eq!(mpu.accel_offset, (0, 0, 0));
+        // 基于未修正输出的标定与温度模型随之失效
+        assert_eq!(mpu.accel_calibration(), AccelCalibration::identity());
+        assert!(mpu.gyro_temp_model().is_none());
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Interface, Mpu6050};
+use crate::config::*;
+use crate::driver::power::{RESET_POLL_ATTEMPTS, RESET_POLL_MS};
+use crate::error::{Mpu6050Error, Result};
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: crate::numeric::NumericType,
+{
+    /// 进入睡眠模式
+    pub fn sleep(&mut self) -> Result<(), DI::Error> {
+        let current = self.read_register(PWR_MGMT_1)?;
+        self.write_register(PWR_MGMT_1, current | PowerMode::Sleep as u8)
+    }
+
+    /// 唤醒设备
+    pub fn wake(&mut self) -> Result<(), DI::Error> {
+        let current = self.read_register(PWR_MGMT_1)?;
+        self.write_register(PWR_MGMT_1, current & !(PowerMode::Sleep as u8))?;
+        self.delay.delay_ms(100);
+        Ok(())
+    }
+
+    /// 重置设备
+    ///
+    /// 轮询 DEVICE_RESET 位直到复位完成，超时返回 [`Mpu6050Error::Timeout`]
+    pub fn reset(&mut self) -> Result<(), DI::Error> {
+        self.write_register(PWR_MGMT_1, DEVICE_RESET)?;
+        for _ in 0..RESET_POLL_ATTEMPTS {
+            self.delay.delay_ms(RESET_POLL_MS);
+            if self.read_register(PWR_MGMT_1)? & DEVICE_RESET == 0 {
+                return Ok(());
+            }
+        }
+        Err(Mpu6050Error::Timeout)
+    }
+}
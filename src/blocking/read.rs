@@ -0,0 +1,194 @@
+use super::{Interface, Mpu6050};
+use crate::driver::convert::{
+    GRAVITY, SENSOR_BURST_LEN, TEMP_GYRO_BURST_LEN, accel_from_raw, compensate_gyro, decode_i16x3,
+    decode_sensor_burst, gyro_from_raw, mount_raw, sensor_data_from_raw, temp_from_raw,
+};
+use crate::error::Result;
+use crate::register::*;
+use crate::{RawSensorData, SensorData, fusion, numeric::NumericType};
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: NumericType,
+{
+    // ================== 数据采集 ==================
+    /// 读取设备ID（WHO_AM_I寄存器），用于检测设备是否连接正常。
+    pub fn who_am_i(&mut self) -> Result<u8, DI::Error> {
+        self.read_register(WHO_AM_I)
+    }
+
+    /// 读取原始加速度计数据（三轴，单位：原始ADC，已按安装方向转换到机体系）
+    pub fn read_accel_raw(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let mut buf = [0u8; 6];
+        self.read_registers(ACCEL_XOUT_H, &mut buf)?;
+        Ok(self.config.mounting.to_body(decode_i16x3(&buf)))
+    }
+
+    /// 读取校准后的加速度计数据（三轴，单位：m/s²）
+    ///
+    /// 依次扣除软件偏移并应用 [`AccelCalibration`](crate::calibration::AccelCalibration)
+    pub fn read_accel(&mut self) -> Result<(T, T, T), DI::Error> {
+        let raw = self.read_accel_raw()?;
+        let accel = accel_from_raw(raw, self.accel_offset, self.config.accel_scale);
+        Ok(self.accel_calibration.apply(accel))
+    }
+
+    /// 读取原始陀螺仪数据（三轴，单位：原始ADC，已按安装方向转换到机体系）
+    pub fn read_gyro_raw(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let mut buf = [0u8; 6];
+        self.read_registers(GYRO_XOUT_H, &mut buf)?;
+        Ok(self.config.mounting.to_body(decode_i16x3(&buf)))
+    }
+
+    /// 读取校准后的陀螺仪数据（三轴，单位：rad/s）
+    ///
+    /// 设置了温度模型时，温度与角速度在一次突发读取中获取并按当前温度扣除零偏
+    pub fn read_gyro(&mut self) -> Result<(T, T, T), DI::Error> {
+        let Some(model) = self.gyro_temp_model else {
+            let raw = self.read_gyro_raw()?;
+            return Ok(gyro_from_raw(raw, self.gyro_offset, self.config.gyro_scale));
+        };
+        let mut buf = [0u8; TEMP_GYRO_BURST_LEN];
+        self.read_registers(TEMP_OUT_H, &mut buf)?;
+        let temp: T = temp_from_raw(i16::from_be_bytes([buf[0], buf[1]]), self.chip);
+        let raw = self.config.mounting.to_body(decode_i16x3(&buf[2..]));
+        let gyro = gyro_from_raw(raw, self.gyro_offset, self.config.gyro_scale);
+        Ok(compensate_gyro(gyro, &model, temp))
+    }
+
+    /// 读取温度（单位：摄氏度）
+    pub fn read_temp(&mut self) -> Result<T, DI::Error> {
+        let mut buf = [0u8; 2];
+        self.read_registers(TEMP_OUT_H, &mut buf)?;
+        Ok(temp_from_raw(i16::from_be_bytes(buf), self.chip))
+    }
+
+    /// 一次突发读取全部原始数据（ACCEL_XOUT_H..GYRO_ZOUT_L，14字节）
+    ///
+    /// 加速度、温度、陀螺仪保证属于同一采样周期，三轴数据已按安装方向转换到机体系
+    pub fn read_all_raw(&mut self) -> Result<RawSensorData, DI::Error> {
+        let raw = self.read_all_raw_chip()?;
+        Ok(mount_raw(raw, &self.config.mounting))
+    }
+
+    /// 芯片坐标系下的突发读取，供片上偏移校准和自检使用
+    pub(crate) fn read_all_raw_chip(&mut self) -> Result<RawSensorData, DI::Error> {
+        let mut buf = [0u8; SENSOR_BURST_LEN];
+        self.read_registers(ACCEL_XOUT_H, &mut buf)?;
+        Ok(decode_sensor_burst(&buf))
+    }
+
+    /// 读取所有传感器数据（加速度、陀螺仪、温度，单位：物理量）
+    ///
+    /// 基于 [`read_all_raw`](Self::read_all_raw) 的单次突发读取
+    pub fn read_all(&mut self) -> Result<SensorData<T>, DI::Error> {
+        let raw = self.read_all_raw()?;
+        Ok(sensor_data_from_raw(
+            &raw,
+            self.accel_offset,
+            self.gyro_offset,
+            &self.accel_calibration,
+            self.gyro_temp_model.as_ref(),
+            &self.config,
+            self.chip,
+        ))
+    }
+
+    // ================== 姿态解算与滤波 ==================
+    /// 仅用加速度计计算 pitch/roll（静态欧拉角，弧度）
+    pub fn calculate_pitch_roll_from_accel(&mut self) -> Result<(T, T), DI::Error> {
+        let accel = self.read_accel()?;
+        Ok(fusion::pitch_roll_from_accel(accel))
+    }
+
+    /// 仅用陀螺仪积分更新 pitch/roll/yaw（弧度）
+    ///
+    /// 机体角速度经欧拉角运动学转换后积分，yaw 包裹到 ±π，坐标系约定见 [`crate::fusion`]
+    pub fn integrate_gyro(&mut self, dt: T) -> Result<(T, T, T), DI::Error> {
+        let gyro = self.read_gyro()?;
+        (self.pitch, self.roll, self.yaw) =
+            fusion::integrate_gyro((self.pitch, self.roll, self.yaw), gyro, dt);
+        Ok((self.pitch, self.roll, self.yaw))
+    }
+
+    /// 互补滤波融合加速度计和陀螺仪（弧度）
+    pub fn complementary_filter(
+        &mut self,
+        acc_pitch: T,
+        acc_roll: T,
+        pitch_g: T,
+        roll_g: T,
+        yaw_g: T,
+        alpha: T,
+    ) {
+        (self.pitch, self.roll, self.yaw) =
+            fusion::complementary_filter(acc_pitch, acc_roll, (pitch_g, roll_g, yaw_g), alpha);
+    }
+
+    /// 获取当前欧拉角（单位：度）
+    pub fn get_euler_angles(&self) -> (T, T, T) {
+        (
+            self.pitch * T::rad_to_deg(),
+            self.roll * T::rad_to_deg(),
+            self.yaw * T::rad_to_deg(),
+        )
+    }
+
+    /// 当前姿态（pitch/roll/yaw）对应的四元数（机体系 → 世界系）
+    pub fn attitude_quaternion(&self) -> fusion::Quaternion<T> {
+        fusion::Quaternion::from_euler(self.pitch, self.roll, self.yaw)
+    }
+
+    /// 读取加速度并按当前姿态去除重力，返回机体系与世界系的线性加速度（m/s²）
+    ///
+    /// 姿态取自 [`update`](Self::update) 维护的 pitch/roll/yaw，调用前应先更新姿态
+    pub fn read_linear_accel(&mut self) -> Result<fusion::LinearAcceleration<T>, DI::Error> {
+        let accel = self.read_accel()?;
+        Ok(fusion::linear_acceleration(
+            &self.attitude_quaternion(),
+            accel,
+            T::from_f32(GRAVITY),
+        ))
+    }
+
+    /// 航位推算单步更新：单次突发读取后互补滤波更新姿态，去除重力并送入 `dead_reckoning`
+    ///
+    /// # 参数
+    /// - `dt`: 采样间隔（秒）
+    /// - `alpha`: 互补滤波系数，同 [`update`](Self::update)
+    pub fn update_dead_reckoning(
+        &mut self,
+        dead_reckoning: &mut fusion::DeadReckoning<T>,
+        dt: T,
+        alpha: T,
+    ) -> Result<(), DI::Error> {
+        let data = self.read_all()?;
+        let gyro_angles = fusion::integrate_gyro((self.pitch, self.roll, self.yaw), data.gyro, dt);
+        let (acc_pitch, acc_roll) = fusion::pitch_roll_from_accel(data.accel);
+        (self.pitch, self.roll, self.yaw) =
+            fusion::complementary_filter(acc_pitch, acc_roll, gyro_angles, alpha);
+
+        let linear = fusion::linear_acceleration(
+            &self.attitude_quaternion(),
+            data.accel,
+            T::from_f32(GRAVITY),
+        );
+        dead_reckoning.update(&linear, data.gyro, dt);
+        Ok(())
+    }
+
+    /// 更新姿态角（pitch/roll/yaw），融合加速度计和陀螺仪数据
+    ///
+    /// 参数与算法同异步版本 [`crate::Mpu6050::update`]
+    pub fn update(&mut self, dt: T, alpha: T) -> Result<(), DI::Error> {
+        let (pitch_g, roll_g, yaw_g) = self.integrate_gyro(dt)?;
+        let (pitch_a, roll_a) = self.calculate_pitch_roll_from_accel()?;
+
+        self.complementary_filter(pitch_a, roll_a, pitch_g, roll_g, yaw_g, alpha);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Interface, Mpu6050};
+use crate::RawSensorData;
+use crate::driver::SelfTestReport;
+use crate::driver::self_test::{
+    SELF_TEST_SAMPLES, SELF_TEST_SETTLE_MS, factory_trim_registers, self_test_report,
+    self_test_scales,
+};
+use crate::error::Result;
+use crate::numeric::NumericType;
+use crate::register::*;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: NumericType,
+{
+    /// 执行硬件自检，流程与判定同异步版本 [`crate::Mpu6050::self_test`]
+    ///
+    /// 完成后恢复原有量程配置，自检期间设备应保持静止。
+    pub fn self_test(&mut self) -> Result<SelfTestReport, DI::Error> {
+        let saved_accel = self.config.accel_scale;
+        let saved_gyro = self.config.gyro_scale;
+        let res = self.run_self_test();
+        // 无论自检是否成功都尝试恢复量程，优先返回自检本身的错误
+        let accel_restored = self.set_accel_scale(saved_accel);
+        let gyro_restored = self.set_gyro_scale(saved_gyro);
+        let report = res?;
+        accel_restored?;
+        gyro_restored?;
+        Ok(report)
+    }
+
+    fn run_self_test(&mut self) -> Result<SelfTestReport, DI::Error> {
+        let (accel_scale, gyro_scale) = self_test_scales(self.chip);
+
+        self.write_register(ACCEL_CONFIG, accel_scale as u8)?;
+        self.write_register(GYRO_CONFIG, gyro_scale as u8)?;
+        self.delay.delay_ms(SELF_TEST_SETTLE_MS);
+        let normal = self.average_raw(SELF_TEST_SAMPLES)?;
+
+        self.write_register(ACCEL_CONFIG, SELF_TEST_ENABLE | accel_scale as u8)?;
+        self.write_register(GYRO_CONFIG, SELF_TEST_ENABLE | gyro_scale as u8)?;
+        self.delay.delay_ms(SELF_TEST_SETTLE_MS);
+        let excited = self.average_raw(SELF_TEST_SAMPLES)?;
+
+        let mut factory = [0u8; 6];
+        let mut pos = 0;
+        for &(reg, len) in factory_trim_registers(self.chip) {
+            self.read_registers(reg, &mut factory[pos..pos + len])?;
+            pos += len;
+        }
+        Ok(self_test_report(self.chip, normal, excited, factory))
+    }
+
+    /// 多次采样求平均：[ax, ay, az, gx, gy, gz]
+    fn average_raw(&mut self, samples: u16) -> Result<[f32; 6], DI::Error> {
+        let mut sum = [0f32; 6];
+        for _ in 0..samples {
+            let RawSensorData { accel, gyro, .. } = self.read_all_raw_chip()?;
+            for (s, v) in sum
+                .iter_mut()
+                .zip([accel.0, accel.1, accel.2, gyro.0, gyro.1, gyro.2])
+            {
+                *s += v as f32;
+            }
+            self.delay.delay_ms(1);
+        }
+        Ok(sum.map(|s| s / samples as f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Mpu6050Config;
+    use crate::error::Mpu6050Error;
+    use crate::interface::mock::{FailingInterface, NoDelay};
+
+    #[test]
+    fn test_self_test_reports_first_error() {
+        let mut mpu: Mpu6050<_, _, f32> = Mpu6050::with_interface(
+            FailingInterface::default(),
+            NoDelay,
+            Mpu6050Config::default(),
+        );
+        assert!(matches!(mpu.self_test(), Err(Mpu6050Error::Bus(0))));
+    }
+}
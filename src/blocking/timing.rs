@@ -0,0 +1,48 @@
+use super::{Interface, Mpu6050};
+use crate::driver::timing::{AutoDt, auto_dt};
+use crate::error::Result;
+use crate::fusion;
+use crate::numeric::NumericType;
+use embassy_time::Instant;
+use embedded_hal::delay::DelayNs;
+
+impl<DI, D, T> Mpu6050<DI, D, T>
+where
+    DI: Interface,
+    D: DelayNs,
+    T: NumericType,
+{
+    /// 芯片实际输出采样周期（秒），计算方式同异步版本 [`crate::Mpu6050::sample_period`]
+    pub fn sample_period(&self) -> T {
+        T::from_f32(self.config.sample_period())
+    }
+
+    /// 清除上次更新时间戳，下一次 [`update_auto`](Self::update_auto) 按首次调用处理
+    pub fn reset_timing(&mut self) {
+        self.last_update = None;
+    }
+
+    /// 自动测量 dt 的姿态更新，返回本次使用的 dt（秒），规则同异步版本 [`crate::Mpu6050::update_auto`]
+    ///
+    /// 时间戳取自 `embassy_time::Instant`，需要链接 embassy-time 时间驱动
+    pub fn update_auto(&mut self, alpha: T) -> Result<T, DI::Error> {
+        let now = Instant::now();
+        let step = auto_dt(self.last_update, now);
+        if step != AutoDt::Skip {
+            self.last_update = Some(now);
+        }
+        match step {
+            AutoDt::Seed | AutoDt::Skip => Ok(T::zero()),
+            AutoDt::Integrate(dt) => {
+                let dt = T::from_f32(dt);
+                self.update(dt, alpha)?;
+                Ok(dt)
+            }
+            AutoDt::Stalled => {
+                let accel = self.read_accel()?;
+                (self.pitch, self.roll) = fusion::pitch_roll_from_accel(accel);
+                Ok(T::zero())
+            }
+        }
+    }
+}
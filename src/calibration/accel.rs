@@ -0,0 +1,51 @@
+//! 加速度计标定结果
+
+use crate::numeric::NumericType;
+
+/// 加速度计标定：`a = M · (a_meas − b)`
+///
+/// `a_meas` 为未扣除软件偏移的读数（m/s²，机体系），
+/// 对角矩阵表示仅有各轴比例因子，非对角项为轴间失准
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccelCalibration {
+    /// 零偏（m/s²）
+    pub bias: [f32; 3],
+    /// 比例因子与失准矩阵（行优先）
+    pub matrix: [[f32; 3]; 3],
+}
+
+impl Default for AccelCalibration {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl AccelCalibration {
+    /// 不做任何修正
+    pub const fn identity() -> Self {
+        Self {
+            bias: [0.0; 3],
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// 各轴比例因子（矩阵对角线）
+    pub fn scale(&self) -> [f32; 3] {
+        [self.matrix[0][0], self.matrix[1][1], self.matrix[2][2]]
+    }
+
+    /// 修正一次加速度读数
+    pub fn apply<T: NumericType>(&self, accel: (T, T, T)) -> (T, T, T) {
+        let v = [
+            accel.0.to_f32() - self.bias[0],
+            accel.1.to_f32() - self.bias[1],
+            accel.2.to_f32() - self.bias[2],
+        ];
+        let m = &self.matrix;
+        (
+            T::from_f32(m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2]),
+            T::from_f32(m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2]),
+            T::from_f32(m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2]),
+        )
+    }
+}
@@ -0,0 +1,155 @@
+//! 带静止检测的陀螺仪零偏标定
+
+use super::RunningStats;
+
+/// 开始检测单样本突变前所需的最少样本数
+const MIN_SAMPLES_FOR_OUTLIER: u32 = 16;
+/// 单样本偏离均值超过 `max_std_dev` 的倍数时立即判定为运动
+const OUTLIER_FACTOR: f32 = 5.0;
+
+/// 陀螺仪静止标定参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GyroCalibrationConfig {
+    /// 有效采样数
+    pub samples: u16,
+    /// 允许的各轴噪声标准差上限（rad/s）
+    pub max_std_dev: f32,
+    /// 检测到运动后重新采集的最长等待时间（毫秒）；`None` 表示检测到运动立即失败
+    pub timeout_ms: Option<u32>,
+}
+
+impl Default for GyroCalibrationConfig {
+    fn default() -> Self {
+        Self {
+            samples: 500,
+            max_std_dev: 0.01,
+            timeout_ms: None,
+        }
+    }
+}
+
+/// 陀螺仪标定结果与质量指标
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GyroCalibrationReport {
+    /// 写入的软件偏移（LSB）
+    pub offset: (i16, i16, i16),
+    /// 各轴噪声标准差（rad/s）
+    pub std_dev: [f32; 3],
+    /// 参与平均的样本数
+    pub samples: u16,
+    /// 因运动而重新采集的次数
+    pub restarts: u16,
+}
+
+/// 单个样本处理结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum StillnessStep {
+    /// 继续采集
+    Collecting,
+    /// 检测到运动，本轮数据作废
+    Moved,
+    /// 采集完成且满足静止条件
+    Done(GyroCalibrationReport),
+}
+
+/// 静止标定状态机，异步与阻塞驱动共用
+pub(crate) struct GyroStillness {
+    config: GyroCalibrationConfig,
+    /// 每 rad/s 对应的 LSB
+    lsb_per_rad: f32,
+    stats: RunningStats,
+    restarts: u16,
+}
+
+impl GyroStillness {
+    pub(crate) fn new(config: GyroCalibrationConfig, lsb_per_rad: f32) -> Self {
+        Self {
+            config,
+            lsb_per_rad,
+            stats: RunningStats::new(),
+            restarts: 0,
+        }
+    }
+
+    /// 丢弃本轮数据重新采集
+    pub(crate) fn restart(&mut self) {
+        self.stats.reset();
+        self.restarts = self.restarts.saturating_add(1);
+    }
+
+    /// 加入一个原始样本（LSB）
+    pub(crate) fn push(&mut self, raw: (i16, i16, i16)) -> StillnessStep {
+        let sample = [raw.0 as f32, raw.1 as f32, raw.2 as f32];
+        let limit = self.config.max_std_dev * self.lsb_per_rad;
+        if self.stats.count() >= MIN_SAMPLES_FOR_OUTLIER {
+            let mean = self.stats.mean();
+            if (0..3).any(|i| (sample[i] - mean[i]).abs() > OUTLIER_FACTOR * limit) {
+                return StillnessStep::Moved;
+            }
+        }
+        self.stats.push(sample);
+        if self.stats.count() < self.config.samples as u32 {
+            return StillnessStep::Collecting;
+        }
+
+        let std_dev = self.stats.std_dev();
+        if std_dev.iter().any(|&s| s > limit) {
+            return StillnessStep::Moved;
+        }
+        let mean = self.stats.mean();
+        let round = |v: f32| libm::roundf(v) as i16;
+        StillnessStep::Done(GyroCalibrationReport {
+            offset: (round(mean[0]), round(mean[1]), round(mean[2])),
+            std_dev: std_dev.map(|s| s / self.lsb_per_rad),
+            samples: self.config.samples,
+            restarts: self.restarts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ±250°/s 下每 rad/s 的 LSB
+    const LSB_PER_RAD: f32 = 131.0 * 180.0 / core::f32::consts::PI;
+
+    fn config() -> GyroCalibrationConfig {
+        GyroCalibrationConfig {
+            samples: 40,
+            ..GyroCalibrationConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_still_device_reports_offset_and_noise() {
+        let mut still = GyroStillness::new(config(), LSB_PER_RAD);
+        let mut result = StillnessStep::Collecting;
+        for i in 0..40 {
+            let noise = if i % 2 == 0 { 1 } else { -1 };
+            result = still.push((20 + noise, -7, 3 + noise));
+        }
+        let StillnessStep::Done(report) = result else {
+            panic!("calibration did not finish: {result:?}");
+        };
+        assert_eq!(report.offset, (20, -7, 3));
+        assert_eq!(report.restarts, 0);
+        assert!(report.std_dev[0] > 0.0 && report.std_dev[1] == 0.0);
+    }
+
+    #[test]
+    fn test_motion_is_detected_and_restart_counted() {
+        let mut still = GyroStillness::new(config(), LSB_PER_RAD);
+        for _ in 0..20 {
+            assert_eq!(still.push((0, 0, 0)), StillnessStep::Collecting);
+        }
+        assert_eq!(still.push((500, 0, 0)), StillnessStep::Moved);
+
+        still.restart();
+        let mut result = StillnessStep::Collecting;
+        for _ in 0..40 {
+            result = still.push((1, 1, 1));
+        }
+        assert!(matches!(result, StillnessStep::Done(r) if r.restarts == 1));
+    }
+}
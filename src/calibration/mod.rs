@@ -0,0 +1,18 @@
+//! 传感器标定
+//!
+//! 纯计算实现，采样由驱动完成，异步与阻塞驱动共用
+
+mod accel;
+mod gyro;
+mod profile;
+mod six_position;
+mod stats;
+mod thermal;
+
+pub use accel::AccelCalibration;
+pub use gyro::{GyroCalibrationConfig, GyroCalibrationReport};
+pub(crate) use gyro::{GyroStillness, StillnessStep};
+pub use profile::CalibrationProfile;
+pub use six_position::{SixPosition, SixPositionCalibrator};
+pub use stats::RunningStats;
+pub use thermal::{GyroTempModel, ThermalCalibrator};
@@ -0,0 +1,297 @@
+//! 可持久化的标定档案
+//!
+//! 二进制格式（小端，共 [`CalibrationProfile::ENCODED_LEN`] 字节）：
+//!
+//! | 偏移 | 长度 | 内容 |
+//! |------|------|------|
+//! | 0    | 4    | 魔数 `MPUC` |
+//! | 4    | 1    | 格式版本 |
+//! | 5    | 1    | 加速度计量程（ACCEL_CONFIG 值） |
+//! | 6    | 1    | 陀螺仪量程（GYRO_CONFIG 值） |
+//! | 7    | 1    | 标志位，bit0：含温度模型 |
+//! | 8    | 6    | 加速度软件偏移 i16×3（LSB） |
+//! | 14   | 6    | 陀螺仪软件偏移 i16×3（LSB） |
+//! | 20   | 12   | 加速度零偏 f32×3（m/s²） |
+//! | 32   | 36   | 加速度比例/失准矩阵 f32×9 |
+//! | 68   | 4    | 温度模型参考温度 f32（°C） |
+//! | 72   | 36   | 温度模型系数 f32×9 |
+//! | 108  | 2    | CRC-16/CCITT-FALSE 校验（覆盖前 108 字节） |
+
+use super::{AccelCalibration, GyroTempModel};
+use crate::config::{AccelScale, GyroScale};
+use crate::error::Mpu6050Error;
+
+const MAGIC: [u8; 4] = *b"MPUC";
+const FLAG_TEMP_MODEL: u8 = 0x01;
+
+/// 标定档案：偏移、比例因子、温度系数及其采集时的量程
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalibrationProfile {
+    /// 采集时的加速度计量程
+    pub accel_scale: AccelScale,
+    /// 采集时的陀螺仪量程
+    pub gyro_scale: GyroScale,
+    /// 加速度软件偏移（LSB，对应 `accel_scale`）
+    pub accel_offset: (i16, i16, i16),
+    /// 陀螺仪软件偏移（LSB，对应 `gyro_scale`）
+    pub gyro_offset: (i16, i16, i16),
+    /// 加速度零偏与比例因子
+    pub accel_calibration: AccelCalibration,
+    /// 陀螺仪零偏温度模型
+    pub gyro_temp_model: Option<GyroTempModel>,
+}
+
+impl CalibrationProfile {
+    /// 当前格式版本
+    pub const FORMAT_VERSION: u8 = 1;
+    /// 编码长度（字节）
+    pub const ENCODED_LEN: usize = 110;
+
+    /// 按指定量程换算软件偏移，返回 `(accel_offset, gyro_offset)`
+    pub fn offsets_for(
+        &self,
+        accel_scale: AccelScale,
+        gyro_scale: GyroScale,
+    ) -> ((i16, i16, i16), (i16, i16, i16)) {
+        let rescale = |v: (i16, i16, i16), ratio: f32| {
+            let f = |x: i16| libm::roundf(x as f32 * ratio) as i16;
+            (f(v.0), f(v.1), f(v.2))
+        };
+        (
+            rescale(
+                self.accel_offset,
+                accel_scale.get_scale_factor() / self.accel_scale.get_scale_factor(),
+            ),
+            rescale(
+                self.gyro_offset,
+                gyro_scale.get_scale_factor() / self.gyro_scale.get_scale_factor(),
+            ),
+        )
+    }
+
+    /// 编码为定长字节数组，可直接写入 Flash/EEPROM
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        let mut w = Writer {
+            buf: &mut out,
+            pos: 0,
+        };
+        w.bytes(&MAGIC);
+        w.bytes(&[
+            Self::FORMAT_VERSION,
+            self.accel_scale as u8,
+            self.gyro_scale as u8,
+            if self.gyro_temp_model.is_some() {
+                FLAG_TEMP_MODEL
+            } else {
+                0
+            },
+        ]);
+        for v in [self.accel_offset, self.gyro_offset] {
+            for x in [v.0, v.1, v.2] {
+                w.bytes(&x.to_le_bytes());
+            }
+        }
+        for x in self.accel_calibration.bias {
+            w.f32(x);
+        }
+        for x in self.accel_calibration.matrix.as_flattened() {
+            w.f32(*x);
+        }
+        let model = self.gyro_temp_model.unwrap_or(GyroTempModel {
+            reference_temp: 0.0,
+            coeffs: [[0.0; 3]; 3],
+        });
+        w.f32(model.reference_temp);
+        for x in model.coeffs.as_flattened() {
+            w.f32(*x);
+        }
+        let crc = crc16(&out[..Self::ENCODED_LEN - 2]);
+        out[Self::ENCODED_LEN - 2..].copy_from_slice(&crc.to_le_bytes());
+        out
+    }
+
+    /// 从字节解码
+    ///
+    /// 长度、魔数、版本、校验或量程字段不合法时返回 [`Mpu6050Error::InvalidProfile`]
+    pub fn from_bytes(bytes: &[u8]) -> core::result::Result<Self, Mpu6050Error<()>> {
+        let bytes = bytes
+            .get(..Self::ENCODED_LEN)
+            .ok_or(Mpu6050Error::InvalidProfile)?;
+        let (body, crc) = bytes.split_at(Self::ENCODED_LEN - 2);
+        if body[..4] != MAGIC
+            || body[4] != Self::FORMAT_VERSION
+            || u16::from_le_bytes([crc[0], crc[1]]) != crc16(body)
+        {
+            return Err(Mpu6050Error::InvalidProfile);
+        }
+        let accel_scale = accel_scale_from_bits(body[5]).ok_or(Mpu6050Error::InvalidProfile)?;
+        let gyro_scale = gyro_scale_from_bits(body[6]).ok_or(Mpu6050Error::InvalidProfile)?;
+        let has_model = body[7] & FLAG_TEMP_MODEL != 0;
+
+        let mut r = Reader { buf: body, pos: 8 };
+        let accel_offset = (r.i16(), r.i16(), r.i16());
+        let gyro_offset = (r.i16(), r.i16(), r.i16());
+        let mut accel_calibration = AccelCalibration::identity();
+        for x in accel_calibration.bias.iter_mut() {
+            *x = r.f32();
+        }
+        for x in accel_calibration.matrix.as_flattened_mut() {
+            *x = r.f32();
+        }
+        let mut model = GyroTempModel {
+            reference_temp: r.f32(),
+            coeffs: [[0.0; 3]; 3],
+        };
+        for x in model.coeffs.as_flattened_mut() {
+            *x = r.f32();
+        }
+
+        Ok(Self {
+            accel_scale,
+            gyro_scale,
+            accel_offset,
+            gyro_offset,
+            accel_calibration,
+            gyro_temp_model: has_model.then_some(model),
+        })
+    }
+}
+
+fn accel_scale_from_bits(bits: u8) -> Option<AccelScale> {
+    [
+        AccelScale::Scale2G,
+        AccelScale::Scale4G,
+        AccelScale::Scale8G,
+        AccelScale::Scale16G,
+    ]
+    .into_iter()
+    .find(|s| *s as u8 == bits)
+}
+
+fn gyro_scale_from_bits(bits: u8) -> Option<GyroScale> {
+    [
+        GyroScale::Scale250,
+        GyroScale::Scale500,
+        GyroScale::Scale1000,
+        GyroScale::Scale2000,
+    ]
+    .into_iter()
+    .find(|s| *s as u8 == bits)
+}
+
+/// CRC-16/CCITT-FALSE（多项式 0x1021，初值 0xFFFF）
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl Writer<'_> {
+    fn bytes(&mut self, data: &[u8]) {
+        self.buf[self.pos..self.pos + data.len()].copy_from_slice(data);
+        self.pos += data.len();
+    }
+
+    fn f32(&mut self, v: f32) {
+        self.bytes(&v.to_le_bytes());
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        out.copy_from_slice(&self.buf[self.pos..self.pos + N]);
+        self.pos += N;
+        out
+    }
+
+    fn i16(&mut self) -> i16 {
+        i16::from_le_bytes(self.take())
+    }
+
+    fn f32(&mut self) -> f32 {
+        f32::from_le_bytes(self.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> CalibrationProfile {
+        CalibrationProfile {
+            accel_scale: AccelScale::Scale4G,
+            gyro_scale: GyroScale::Scale500,
+            accel_offset: (120, -40, 8),
+            gyro_offset: (-30, 15, 2),
+            accel_calibration: AccelCalibration {
+                bias: [0.1, -0.2, 0.3],
+                matrix: [[1.01, 0.002, 0.0], [0.0, 0.99, -0.001], [0.003, 0.0, 1.02]],
+            },
+            gyro_temp_model: Some(GyroTempModel {
+                reference_temp: 25.0,
+                coeffs: [[0.01, 1e-4, 1e-6], [0.0; 3], [-0.02, 2e-4, 0.0]],
+            }),
+        }
+    }
+
+    #[test]
+    fn test_crc16_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let profile = sample();
+        let bytes = profile.to_bytes();
+        assert_eq!(&bytes[..4], b"MPUC");
+        assert_eq!(CalibrationProfile::from_bytes(&bytes), Ok(profile));
+
+        let no_model = CalibrationProfile {
+            gyro_temp_model: None,
+            ..profile
+        };
+        assert_eq!(
+            CalibrationProfile::from_bytes(&no_model.to_bytes()),
+            Ok(no_model)
+        );
+    }
+
+    #[test]
+    fn test_rejects_corruption() {
+        let mut bytes = sample().to_bytes();
+        assert!(CalibrationProfile::from_bytes(&bytes[..50]).is_err());
+        bytes[20] ^= 0x01;
+        assert_eq!(
+            CalibrationProfile::from_bytes(&bytes),
+            Err(Mpu6050Error::InvalidProfile)
+        );
+    }
+
+    #[test]
+    fn test_offsets_rescaled_to_current_range() {
+        let (accel, gyro) = sample().offsets_for(AccelScale::Scale2G, GyroScale::Scale1000);
+        assert_eq!(accel, (240, -80, 16));
+        assert_eq!(gyro, (-15, 8, 1));
+    }
+}
@@ -0,0 +1,210 @@
+//! 六面法加速度计标定
+//!
+//! 依次将设备的 ±X、±Y、±Z 轴朝上静置，记录每个姿态下的平均读数，
+//! 由六组读数解算零偏、比例因子和（可选）轴间失准。
+
+use super::AccelCalibration;
+use crate::driver::convert::GRAVITY;
+use crate::util::inverse3;
+
+/// 标定姿态：朝上的机体轴
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SixPosition {
+    XUp,
+    XDown,
+    YUp,
+    YDown,
+    ZUp,
+    ZDown,
+}
+
+impl SixPosition {
+    /// 推荐的采集顺序
+    pub const ALL: [SixPosition; 6] = [
+        SixPosition::ZUp,
+        SixPosition::ZDown,
+        SixPosition::XUp,
+        SixPosition::XDown,
+        SixPosition::YUp,
+        SixPosition::YDown,
+    ];
+
+    /// 朝上的轴下标
+    pub fn axis(self) -> usize {
+        match self {
+            SixPosition::XUp | SixPosition::XDown => 0,
+            SixPosition::YUp | SixPosition::YDown => 1,
+            SixPosition::ZUp | SixPosition::ZDown => 2,
+        }
+    }
+
+    /// 该轴朝上为 `true`，朝下为 `false`
+    pub fn is_up(self) -> bool {
+        matches!(self, SixPosition::XUp | SixPosition::YUp | SixPosition::ZUp)
+    }
+
+    fn slot(self) -> usize {
+        self.axis() * 2 + if self.is_up() { 0 } else { 1 }
+    }
+
+    /// 读数是否与该姿态相符：重力主要落在对应轴上且符号正确
+    pub fn matches(self, mean: [f32; 3]) -> bool {
+        let axis = self.axis();
+        let value = if self.is_up() {
+            mean[axis]
+        } else {
+            -mean[axis]
+        };
+        value > 0.0 && (0..3).all(|i| i == axis || mean[i].abs() < value)
+    }
+}
+
+/// 六面法采集与解算
+#[derive(Debug, Clone, Default)]
+pub struct SixPositionCalibrator {
+    means: [Option<[f32; 3]>; 6],
+}
+
+impl SixPositionCalibrator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录某姿态的平均读数（m/s²，未扣除软件偏移），重复记录会覆盖
+    ///
+    /// 读数与姿态不符时返回 `false` 且不记录
+    pub fn record(&mut self, position: SixPosition, mean: [f32; 3]) -> bool {
+        if !position.matches(mean) {
+            return false;
+        }
+        self.means[position.slot()] = Some(mean);
+        true
+    }
+
+    /// 下一个尚未采集的姿态，按 [`SixPosition::ALL`] 顺序
+    pub fn next_position(&self) -> Option<SixPosition> {
+        SixPosition::ALL
+            .into_iter()
+            .find(|p| self.means[p.slot()].is_none())
+    }
+
+    /// 六个姿态是否均已采集
+    pub fn is_complete(&self) -> bool {
+        self.next_position().is_none()
+    }
+
+    /// 清空已采集数据
+    pub fn reset(&mut self) {
+        self.means = [None; 6];
+    }
+
+    /// 解算标定结果
+    ///
+    /// `misalignment` 为 `true` 时求解完整 3×3 矩阵（含轴间失准），否则只求各轴比例因子。
+    /// 数据不完整或矩阵奇异时返回 `None`
+    pub fn solve(&self, misalignment: bool) -> Option<AccelCalibration> {
+        let mut up = [[0.0f32; 3]; 3];
+        let mut down = [[0.0f32; 3]; 3];
+        for axis in 0..3 {
+            up[axis] = self.means[axis * 2]?;
+            down[axis] = self.means[axis * 2 + 1]?;
+        }
+
+        let mut cal = AccelCalibration::identity();
+        if misalignment {
+            // 每对相反姿态的中点都是零偏的估计，取平均
+            for i in 0..3 {
+                cal.bias[i] = (0..3).map(|j| up[j][i] + down[j][i]).sum::<f32>() / 6.0;
+            }
+            // M · (up_j − down_j) = 2g·e_j，即 M = 2g · D⁻¹
+            let mut d = [[0.0f32; 3]; 3];
+            for (j, (u, w)) in up.iter().zip(down.iter()).enumerate() {
+                for i in 0..3 {
+                    d[i][j] = u[i] - w[i];
+                }
+            }
+            let inv = inverse3(&d, f32::EPSILON)?;
+            for (row, inv_row) in cal.matrix.iter_mut().zip(inv.iter()) {
+                for (m, v) in row.iter_mut().zip(inv_row.iter()) {
+                    *m = 2.0 * GRAVITY * v;
+                }
+            }
+        } else {
+            for i in 0..3 {
+                let span = up[i][i] - down[i][i];
+                if span <= 0.0 {
+                    return None;
+                }
+                cal.bias[i] = (up[i][i] + down[i][i]) / 2.0;
+                cal.matrix[i][i] = 2.0 * GRAVITY / span;
+            }
+        }
+        Some(cal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按 `a_meas = A · a_true + b` 生成六面读数
+    fn simulate(a: [[f32; 3]; 3], b: [f32; 3]) -> SixPositionCalibrator {
+        let mut cal = SixPositionCalibrator::new();
+        while let Some(position) = cal.next_position() {
+            let mut truth = [0.0f32; 3];
+            truth[position.axis()] = if position.is_up() { GRAVITY } else { -GRAVITY };
+            let mut mean = b;
+            for i in 0..3 {
+                for j in 0..3 {
+                    mean[i] += a[i][j] * truth[j];
+                }
+            }
+            assert!(cal.record(position, mean));
+        }
+        cal
+    }
+
+    #[test]
+    fn test_solve_bias_and_scale() {
+        let cal = simulate(
+            [[1.02, 0.0, 0.0], [0.0, 0.97, 0.0], [0.0, 0.0, 1.05]],
+            [0.3, -0.2, 0.5],
+        );
+        let result = cal.solve(false).unwrap();
+        let scale = result.scale();
+        assert!((result.bias[0] - 0.3).abs() < 1e-4);
+        assert!((result.bias[2] - 0.5).abs() < 1e-4);
+        assert!((scale[0] - 1.0 / 1.02).abs() < 1e-4);
+        assert!((scale[1] - 1.0 / 0.97).abs() < 1e-4);
+
+        let corrected = result.apply((0.3f32, -0.2, 0.5 + 1.05 * GRAVITY));
+        assert!(corrected.0.abs() < 1e-4 && (corrected.2 - GRAVITY).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_solve_misalignment() {
+        let a = [
+            [1.01, 0.02, -0.01],
+            [0.015, 0.98, 0.03],
+            [-0.02, 0.01, 1.03],
+        ];
+        let result = simulate(a, [0.1, 0.2, -0.3]).solve(true).unwrap();
+        // M·A 应为单位阵
+        for i in 0..3 {
+            for j in 0..3 {
+                let v: f32 = (0..3).map(|k| result.matrix[i][k] * a[k][j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((v - expected).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_wrong_orientation_and_incomplete() {
+        let mut cal = SixPositionCalibrator::new();
+        assert!(!cal.record(SixPosition::ZUp, [0.0, 0.0, -GRAVITY]));
+        assert!(cal.record(SixPosition::ZUp, [0.1, 0.0, GRAVITY]));
+        assert_eq!(cal.next_position(), Some(SixPosition::ZDown));
+        assert!(cal.solve(false).is_none());
+    }
+}
@@ -0,0 +1,73 @@
+//! 三轴在线均值/方差统计（Welford 算法）
+
+/// 三轴在线统计，逐样本更新，数值稳定
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RunningStats {
+    count: u32,
+    mean: [f32; 3],
+    m2: [f32; 3],
+}
+
+impl RunningStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加入一个样本
+    pub fn push(&mut self, sample: [f32; 3]) {
+        self.count += 1;
+        let n = self.count as f32;
+        for ((mean, m2), x) in self.mean.iter_mut().zip(self.m2.iter_mut()).zip(sample) {
+            let delta = x - *mean;
+            *mean += delta / n;
+            *m2 += delta * (x - *mean);
+        }
+    }
+
+    /// 清空统计
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 样本数
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// 均值
+    pub fn mean(&self) -> [f32; 3] {
+        self.mean
+    }
+
+    /// 样本方差（n − 1），样本数不足2时为0
+    pub fn variance(&self) -> [f32; 3] {
+        if self.count < 2 {
+            return [0.0; 3];
+        }
+        let n = (self.count - 1) as f32;
+        [self.m2[0] / n, self.m2[1] / n, self.m2[2] / n]
+    }
+
+    /// 标准差
+    pub fn std_dev(&self) -> [f32; 3] {
+        self.variance().map(libm::sqrtf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut stats = RunningStats::new();
+        for x in [2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.push([x, -x, 1.0]);
+        }
+        assert_eq!(stats.count(), 8);
+        assert!((stats.mean()[0] - 5.0).abs() < 1e-6);
+        assert!((stats.mean()[1] + 5.0).abs() < 1e-6);
+        assert!((stats.variance()[0] - 32.0 / 7.0).abs() < 1e-5);
+        assert_eq!(stats.std_dev()[2], 0.0);
+    }
+}
@@ -0,0 +1,166 @@
+//! 陀螺仪零偏温度模型与拟合
+
+/// 陀螺仪零偏随温度变化的多项式模型
+///
+/// 每轴 `bias(t) = c0 + c1·Δt + c2·Δt²`（rad/s），`Δt = t − reference_temp`（°C）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GyroTempModel {
+    /// 参考温度（°C）
+    pub reference_temp: f32,
+    /// 各轴多项式系数 `[c0, c1, c2]`
+    pub coeffs: [[f32; 3]; 3],
+}
+
+impl GyroTempModel {
+    /// 指定温度下的三轴零偏（rad/s）
+    pub fn bias_at(&self, temp: f32) -> [f32; 3] {
+        let dt = temp - self.reference_temp;
+        self.coeffs.map(|[c0, c1, c2]| c0 + dt * (c1 + dt * c2))
+    }
+}
+
+/// 多温度点零偏记录与多项式拟合
+#[derive(Debug, Clone, Default)]
+pub struct ThermalCalibrator {
+    points: [(f32, [f32; 3]); Self::CAPACITY],
+    len: usize,
+}
+
+impl ThermalCalibrator {
+    /// 最多记录的温度点数
+    pub const CAPACITY: usize = 16;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一个温度点：温度（°C）与该温度下的三轴零偏（rad/s）
+    ///
+    /// 已满时返回 `false`
+    pub fn add_point(&mut self, temp: f32, bias: [f32; 3]) -> bool {
+        if self.len == Self::CAPACITY {
+            return false;
+        }
+        self.points[self.len] = (temp, bias);
+        self.len += 1;
+        true
+    }
+
+    /// 已记录的点数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 是否尚未记录任何点
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 清空记录
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// 最小二乘拟合，`order` 取 0~2（超出按2处理），参考温度取各点平均温度
+    ///
+    /// 点数不足 `order + 1` 或温度点过于集中导致方程奇异时返回 `None`
+    pub fn fit(&self, order: usize) -> Option<GyroTempModel> {
+        let order = order.min(2);
+        let points = &self.points[..self.len];
+        if points.len() <= order {
+            return None;
+        }
+        let reference_temp = points.iter().map(|p| p.0).sum::<f32>() / points.len() as f32;
+
+        // 法方程 AᵀA·c = Aᵀb，A 的行为 [1, Δt, Δt²]
+        let n = order + 1;
+        let mut ata = [[0.0f32; 3]; 3];
+        let mut atb = [[0.0f32; 3]; 3];
+        for (temp, bias) in points {
+            let dt = temp - reference_temp;
+            let row = [1.0, dt, dt * dt];
+            for i in 0..n {
+                for j in 0..n {
+                    ata[i][j] += row[i] * row[j];
+                }
+                for (axis, b) in bias.iter().enumerate() {
+                    atb[axis][i] += row[i] * b;
+                }
+            }
+        }
+
+        let mut coeffs = [[0.0f32; 3]; 3];
+        for (axis, c) in coeffs.iter_mut().enumerate() {
+            let solution = solve(&ata, &atb[axis], n)?;
+            c[..n].copy_from_slice(&solution[..n]);
+        }
+        Some(GyroTempModel {
+            reference_temp,
+            coeffs,
+        })
+    }
+}
+
+/// 高斯消元求解 n×n（n ≤ 3）线性方程组，主元过小时返回 `None`
+fn solve(a: &[[f32; 3]; 3], b: &[f32; 3], n: usize) -> Option<[f32; 3]> {
+    let mut m = *a;
+    let mut x = *b;
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| m[i][col].abs().total_cmp(&m[j][col].abs()))?;
+        if m[pivot][col].abs() < 1e-6 {
+            return None;
+        }
+        m.swap(col, pivot);
+        x.swap(col, pivot);
+        for row in (col + 1)..n {
+            let factor = m[row][col] / m[col][col];
+            let pivot_row = m[col];
+            for (dst, src) in m[row][col..n].iter_mut().zip(&pivot_row[col..n]) {
+                *dst -= factor * src;
+            }
+            x[row] -= factor * x[col];
+        }
+    }
+    for col in (0..n).rev() {
+        for k in (col + 1)..n {
+            x[col] -= m[col][k] * x[k];
+        }
+        x[col] /= m[col][col];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_quadratic_recovers_model() {
+        let truth = GyroTempModel {
+            reference_temp: 30.0,
+            coeffs: [[0.01, 2e-4, -3e-6], [-0.02, 0.0, 1e-6], [0.005, -1e-4, 0.0]],
+        };
+        let mut cal = ThermalCalibrator::new();
+        for temp in [15.0, 22.0, 30.0, 38.0, 45.0] {
+            assert!(cal.add_point(temp, truth.bias_at(temp)));
+        }
+        let model = cal.fit(2).unwrap();
+        for temp in [18.0, 33.0, 50.0] {
+            let (expected, actual) = (truth.bias_at(temp), model.bias_at(temp));
+            for axis in 0..3 {
+                assert!((expected[axis] - actual[axis]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fit_requires_enough_distinct_points() {
+        let mut cal = ThermalCalibrator::new();
+        assert!(cal.fit(1).is_none());
+        cal.add_point(25.0, [0.01; 3]);
+        cal.add_point(25.0, [0.01; 3]);
+        assert!(cal.fit(1).is_none());
+        let constant = cal.fit(0).unwrap();
+        assert_eq!(constant.bias_at(40.0), [0.01; 3]);
+    }
+}
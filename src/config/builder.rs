@@ -16,6 +16,8 @@ pub struct ConfigBuilder {
     enable_interrupts: bool,
     enable_fifo: bool,
     low_power_mode: bool,
+    mounting: Mounting,
+    fifo_channels: FifoChannels,
 }
 
 impl Default for ConfigBuilder {
@@ -36,6 +38,8 @@ impl ConfigBuilder {
             enable_interrupts: false,
             enable_fifo: false,
             low_power_mode: false,
+            mounting: Mounting::Identity,
+            fifo_channels: FifoChannels::default(),
         }
     }
 
@@ -87,12 +91,24 @@ impl ConfigBuilder {
         self
     }
 
+    /// 设置写入FIFO的通道
+    pub fn fifo_channels(mut self, channels: FifoChannels) -> Self {
+        self.fifo_channels = channels;
+        self
+    }
+
     /// 启用低功耗模式
     pub fn low_power_mode(mut self, enable: bool) -> Self {
         self.low_power_mode = enable;
         self
     }
 
+    /// 设置安装方向（芯片坐标系 → 机体坐标系）
+    pub fn mounting(mut self, mounting: Mounting) -> Self {
+        self.mounting = mounting;
+        self
+    }
+
     /// 构建配置
     pub fn build(self) -> core::result::Result<Mpu6050Config, Mpu6050Error<()>> {
         let config = Mpu6050Config {
@@ -104,6 +120,8 @@ impl ConfigBuilder {
             enable_interrupts: self.enable_interrupts,
             enable_fifo: self.enable_fifo,
             low_power_mode: self.low_power_mode,
+            mounting: self.mounting,
+            fifo_channels: self.fifo_channels,
         };
 
         config.validate()?;
@@ -121,6 +139,8 @@ impl ConfigBuilder {
             enable_interrupts: self.enable_interrupts,
             enable_fifo: self.enable_fifo,
             low_power_mode: self.low_power_mode,
+            mounting: self.mounting,
+            fifo_channels: self.fifo_channels,
         }
     }
 }
@@ -187,6 +207,16 @@ impl ConfigValidation for Mpu6050Config {
             return Err(Mpu6050Error::InvalidConfig);
         }
 
+        // 验证安装矩阵为合法旋转
+        if !self.mounting.is_valid() {
+            return Err(Mpu6050Error::InvalidConfig);
+        }
+
+        // 启用FIFO时至少选择一个通道
+        if self.enable_fifo && self.fifo_channels.is_empty() {
+            return Err(Mpu6050Error::InvalidConfig);
+        }
+
         // 验证FIFO和中断的兼容性
         if self.enable_fifo && !self.enable_interrupts {
             // FIFO通常需要中断来指示数据就绪
@@ -215,9 +245,16 @@ mod tests {
         let config = ConfigBuilder::new().sample_rate(100).build();
         assert!(config.is_ok());
 
-        // 无效采样率
+        // 超出范围的采样率被限制到 4-1000 Hz
         let config = ConfigBuilder::new().sample_rate(2000).build();
-        assert!(config.is_err());
+        assert_eq!(config.unwrap().sample_rate, 1000);
+
+        // 直接构造的无效采样率
+        let config = Mpu6050Config {
+            sample_rate: 2000,
+            ..ConfigBuilder::new().build_unchecked()
+        };
+        assert!(config.validate().is_err());
     }
 
     #[test]
@@ -232,4 +269,31 @@ mod tests {
         let low_power = ConfigBuilder::low_power().build_unchecked();
         assert!(low_power.low_power_mode);
     }
+
+    #[test]
+    fn test_mounting_validation() {
+        let flipped = Mounting::axes(MountAxis::PosX, MountAxis::NegY).unwrap();
+        assert!(ConfigBuilder::new().sample_rate(100).mounting(flipped).build().is_ok());
+
+        let skewed = Mounting::matrix([[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert!(ConfigBuilder::new().sample_rate(100).mounting(skewed).build().is_err());
+    }
+
+    #[test]
+    fn test_fifo_channels_validation() {
+        let config = ConfigBuilder::new()
+            .sample_rate(100)
+            .enable_fifo(true)
+            .fifo_channels(FifoChannels::ACCEL | FifoChannels::TEMP)
+            .build()
+            .unwrap();
+        assert_eq!(config.fifo_channels.frame_len(), 8);
+
+        let empty = ConfigBuilder::new()
+            .sample_rate(100)
+            .enable_fifo(true)
+            .fifo_channels(FifoChannels::NONE)
+            .build();
+        assert!(empty.is_err());
+    }
 }
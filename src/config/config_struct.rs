@@ -11,6 +11,8 @@ pub struct ConfigBuilder {
     sample_rate: u16,
     clock_source: ClockSource,
     enable_interrupts: bool,
+    mounting: super::Mounting,
+    fifo_channels: super::FifoChannels,
 }
 
 impl Default for ConfigBuilder {
@@ -22,6 +24,8 @@ impl Default for ConfigBuilder {
             sample_rate: 1000,
             clock_source: ClockSource::Internal,
             enable_interrupts: false,
+            mounting: super::Mounting::Identity,
+            fifo_channels: super::FifoChannels::default(),
         }
     }
 }
@@ -54,6 +58,14 @@ impl ConfigBuilder {
         self.enable_interrupts = enable_interrupts;
         self
     }
+    pub fn mounting(mut self, mounting: super::Mounting) -> Self {
+        self.mounting = mounting;
+        self
+    }
+    pub fn fifo_channels(mut self, fifo_channels: super::FifoChannels) -> Self {
+        self.fifo_channels = fifo_channels;
+        self
+    }
     pub fn build(self) -> Mpu6050Config {
         Mpu6050Config {
             accel_scale: self.accel_scale,
@@ -64,6 +76,8 @@ impl ConfigBuilder {
             enable_interrupts: self.enable_interrupts,
             enable_fifo: false,
             low_power_mode: false,
+            mounting: self.mounting,
+            fifo_channels: self.fifo_channels,
         }
     }
 }
@@ -78,6 +92,10 @@ pub struct Mpu6050Config {
     pub enable_interrupts: bool,
     pub enable_fifo: bool,
     pub low_power_mode: bool,
+    /// 安装方向，读数在解码时转换到机体系
+    pub mounting: super::Mounting,
+    /// 写入 FIFO 的通道，决定帧格式
+    pub fifo_channels: super::FifoChannels,
 }
 
 impl Default for Mpu6050Config {
@@ -91,6 +109,8 @@ impl Default for Mpu6050Config {
             enable_interrupts: false,
             enable_fifo: false,
             low_power_mode: false,
+            mounting: super::Mounting::Identity,
+            fifo_channels: super::FifoChannels::default(),
         }
     }
 }
@@ -99,15 +119,53 @@ impl Mpu6050Config {
     pub fn new(builder: ConfigBuilder) -> Self {
         builder.build()
     }
+
+    /// 按当前 DLPF 对应的陀螺仪输出频率计算 SMPLRT_DIV
+    pub fn sample_rate_divider(&self) -> u8 {
+        divider_for(self.dlpf_config.gyro_output_rate(), self.sample_rate)
+    }
+
+    /// 芯片实际输出采样周期（秒）：(1 + SMPLRT_DIV) / 陀螺仪输出频率
+    pub fn sample_period(&self) -> f32 {
+        (1.0 + self.sample_rate_divider() as f32) / self.dlpf_config.gyro_output_rate() as f32
+    }
 }
 
+/// 按 1kHz 陀螺仪输出频率（DLPF 启用）计算分频值，DLPF 关闭时使用
+/// [`Mpu6050Config::sample_rate_divider`]
 pub fn calculate_sample_rate_divider(desired_rate: u16) -> u8 {
-    // MPU6050内部时钟为1kHz，采样率 = 1000 / (1 + SMPLRT_DIV)
-    if desired_rate >= 1000 {
-        0
-    } else if desired_rate <= 1 {
-        255
-    } else {
-        ((1000 / desired_rate) - 1) as u8
+    divider_for(1000, desired_rate)
+}
+
+/// 采样率 = 输出频率 / (1 + SMPLRT_DIV)
+fn divider_for(output_rate: u16, desired_rate: u16) -> u8 {
+    let div = (output_rate / desired_rate.max(1)).saturating_sub(1);
+    div.min(u8::MAX as u16) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_rate_divider_follows_dlpf() {
+        assert_eq!(calculate_sample_rate_divider(1000), 0);
+        assert_eq!(calculate_sample_rate_divider(200), 4);
+        assert_eq!(calculate_sample_rate_divider(1), 255);
+
+        let mut config = Mpu6050Config {
+            sample_rate: 1000,
+            ..Mpu6050Config::default()
+        };
+        assert_eq!(config.sample_rate_divider(), 0);
+        assert!((config.sample_period() - 0.001).abs() < 1e-9);
+
+        config.dlpf_config = crate::config::DlpfConfig::Disabled;
+        assert_eq!(config.sample_rate_divider(), 7);
+        assert!((config.sample_period() - 0.001).abs() < 1e-9);
+
+        config.sample_rate = 4;
+        assert_eq!(config.sample_rate_divider(), 255);
+        assert!((config.sample_period() - 0.032).abs() < 1e-9);
     }
 }
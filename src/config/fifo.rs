@@ -0,0 +1,105 @@
+//! FIFO 通道选择
+
+use super::FifoEnType;
+
+/// 写入 FIFO 的通道集合（FIFO_EN 寄存器位），可用 `|` 组合
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FifoChannels(u8);
+
+impl FifoChannels {
+    /// 不写入任何通道
+    pub const NONE: Self = Self(0x00);
+    /// 温度
+    pub const TEMP: Self = Self(0x80);
+    /// 陀螺仪 X 轴
+    pub const GYRO_X: Self = Self(0x40);
+    /// 陀螺仪 Y 轴
+    pub const GYRO_Y: Self = Self(0x20);
+    /// 陀螺仪 Z 轴
+    pub const GYRO_Z: Self = Self(0x10);
+    /// 加速度计三轴
+    pub const ACCEL: Self = Self(0x08);
+    /// 陀螺仪三轴
+    pub const GYRO: Self = Self(0x70);
+    /// 全部六轴数据与温度
+    pub const ALL: Self = Self(0xF8);
+
+    /// 由 FIFO_EN 寄存器值构造，忽略非传感器通道位
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    /// FIFO_EN 寄存器值
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// 是否包含 `other` 的全部通道
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// 是否为空
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// 单帧字节数：加速度6、温度2、每个陀螺仪轴2
+    pub const fn frame_len(self) -> usize {
+        let mut len = 0;
+        if self.0 & Self::ACCEL.0 != 0 {
+            len += 6;
+        }
+        len + 2 * (self.0 & (Self::TEMP.0 | Self::GYRO.0)).count_ones() as usize
+    }
+}
+
+impl Default for FifoChannels {
+    /// 默认加速度计 + 陀螺仪三轴
+    fn default() -> Self {
+        Self::ACCEL | Self::GYRO
+    }
+}
+
+impl core::ops::BitOr for FifoChannels {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for FifoChannels {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<FifoEnType> for FifoChannels {
+    fn from(ty: FifoEnType) -> Self {
+        Self::from_bits(ty as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channels_and_frame_len() {
+        assert_eq!(FifoChannels::default().bits(), 0x78);
+        assert_eq!(FifoChannels::default().frame_len(), 12);
+        assert_eq!(FifoChannels::ALL.frame_len(), 14);
+        assert_eq!((FifoChannels::TEMP | FifoChannels::GYRO_Z).frame_len(), 4);
+        assert!(FifoChannels::ALL.contains(FifoChannels::GYRO));
+        assert!(!FifoChannels::ACCEL.contains(FifoChannels::GYRO_X));
+        assert_eq!(
+            FifoChannels::from(FifoEnType::GyroXout),
+            FifoChannels::GYRO_X
+        );
+        assert_eq!(
+            FifoChannels::from(FifoEnType::FifiDisable),
+            FifoChannels::NONE
+        );
+    }
+}
@@ -7,13 +7,17 @@ pub use clock::*;
 pub use config_struct::{
     AccelScale, ClockSource, ConfigBuilder, GyroScale, Mpu6050Config, calculate_sample_rate_divider,
 };
+pub use fifo::FifoChannels;
 pub use interrupt::*;
+pub use mounting::{AxisRemap, MountAxis, Mounting};
 pub use scale::*;
 
 mod builder;
 mod clock;
 mod config_struct;
+mod fifo;
 mod interrupt;
+mod mounting;
 mod scale;
 
 // 陀螺仪类型
@@ -34,14 +38,14 @@ pub enum AccelType {
     Acc10g = 0x18,
 }
 
-// FIFO 使能类型
+// FIFO 使能类型（FIFO_EN 寄存器位），组合多个通道见 [`FifoChannels`]
 #[derive(Debug, Clone)]
 pub enum FifoEnType {
     FifiDisable = 0x00,
     AccOut = 0x08,
-    GyroXout = 0x10,
+    GyroZout = 0x10,
     GyroYout = 0x20,
-    GyroZout = 0x40,
+    GyroXout = 0x40,
     TempOut = 0x80,
 }
 
@@ -56,3 +60,15 @@ pub enum DlpfConfig {
     Bandwidth10Hz = 0x05,
     Bandwidth5Hz = 0x06,
 }
+
+impl DlpfConfig {
+    /// 陀螺仪输出频率（Hz），即 SMPLRT_DIV 分频前的基准频率：DLPF 关闭时 8kHz，否则 1kHz
+    ///
+    /// MPU6050 加速度计输出频率固定为 1kHz，基准高于此值时加速度计读数会重复
+    pub fn gyro_output_rate(&self) -> u16 {
+        match self {
+            DlpfConfig::Disabled => 8000,
+            _ => 1000,
+        }
+    }
+}
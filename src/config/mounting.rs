@@ -0,0 +1,241 @@
+//! 传感器安装方向（芯片坐标系 → 机体坐标系）
+
+/// 芯片的带符号坐标轴
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MountAxis {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+impl MountAxis {
+    fn to_vector(self) -> [i8; 3] {
+        match self {
+            MountAxis::PosX => [1, 0, 0],
+            MountAxis::NegX => [-1, 0, 0],
+            MountAxis::PosY => [0, 1, 0],
+            MountAxis::NegY => [0, -1, 0],
+            MountAxis::PosZ => [0, 0, 1],
+            MountAxis::NegZ => [0, 0, -1],
+        }
+    }
+
+    fn from_vector(v: [i8; 3]) -> Option<Self> {
+        match v {
+            [1, 0, 0] => Some(MountAxis::PosX),
+            [-1, 0, 0] => Some(MountAxis::NegX),
+            [0, 1, 0] => Some(MountAxis::PosY),
+            [0, -1, 0] => Some(MountAxis::NegY),
+            [0, 0, 1] => Some(MountAxis::PosZ),
+            [0, 0, -1] => Some(MountAxis::NegZ),
+            _ => None,
+        }
+    }
+
+    /// 芯片轴下标
+    fn index(self) -> usize {
+        match self {
+            MountAxis::PosX | MountAxis::NegX => 0,
+            MountAxis::PosY | MountAxis::NegY => 1,
+            MountAxis::PosZ | MountAxis::NegZ => 2,
+        }
+    }
+
+    fn is_negative(self) -> bool {
+        matches!(self, MountAxis::NegX | MountAxis::NegY | MountAxis::NegZ)
+    }
+}
+
+/// 轴对齐安装：机体 x/y/z 轴分别对应的芯片轴
+///
+/// 只能通过 [`Mounting::axes`] 构造，保证是 24 种合法旋转之一
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisRemap {
+    axes: [MountAxis; 3],
+}
+
+impl AxisRemap {
+    /// 机体 x/y/z 轴对应的芯片轴
+    pub fn axes(&self) -> [MountAxis; 3] {
+        self.axes
+    }
+}
+
+/// 安装方向：机体系向量 = R · 芯片系向量
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Mounting {
+    /// 芯片轴与机体轴一致
+    #[default]
+    Identity,
+    /// 24 种轴对齐旋转之一，整型原始数据可无损变换
+    Remap(AxisRemap),
+    /// 任意旋转矩阵 R（行优先），原始数据变换后四舍五入
+    Matrix([[f32; 3]; 3]),
+}
+
+impl Mounting {
+    /// 由机体 x、y 轴对应的芯片轴构造轴对齐安装，z 轴由右手定则确定
+    ///
+    /// 例如芯片翻面安装：`Mounting::axes(MountAxis::PosX, MountAxis::NegY)`。
+    /// `x` 与 `y` 共线时返回 `None`
+    pub fn axes(x: MountAxis, y: MountAxis) -> Option<Self> {
+        let a = x.to_vector();
+        let b = y.to_vector();
+        let z = [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ];
+        let z = MountAxis::from_vector(z)?;
+        Some(Mounting::Remap(AxisRemap { axes: [x, y, z] }))
+    }
+
+    /// 任意旋转矩阵安装，矩阵合法性在配置校验时检查
+    pub fn matrix(rotation: [[f32; 3]; 3]) -> Self {
+        Mounting::Matrix(rotation)
+    }
+
+    /// 旋转矩阵形式
+    pub fn rotation_matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            Mounting::Identity => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            Mounting::Remap(remap) => {
+                let mut m = [[0.0; 3]; 3];
+                for (row, axis) in m.iter_mut().zip(remap.axes) {
+                    for (dst, src) in row.iter_mut().zip(axis.to_vector()) {
+                        *dst = src as f32;
+                    }
+                }
+                m
+            }
+            Mounting::Matrix(m) => *m,
+        }
+    }
+
+    /// 是否为合法旋转矩阵（正交且行列式为 +1，容差 1e-3）
+    pub fn is_valid(&self) -> bool {
+        let m = self.rotation_matrix();
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot: f32 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if (dot - expected).abs() > 1e-3 {
+                    return false;
+                }
+            }
+        }
+        let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        det > 0.0
+    }
+
+    /// 芯片系原始数据 → 机体系
+    pub fn to_body(&self, v: (i16, i16, i16)) -> (i16, i16, i16) {
+        match self {
+            Mounting::Identity => v,
+            Mounting::Remap(remap) => {
+                let src = [v.0, v.1, v.2];
+                let pick = |axis: MountAxis| {
+                    let val = src[axis.index()];
+                    if axis.is_negative() {
+                        val.saturating_neg()
+                    } else {
+                        val
+                    }
+                };
+                (
+                    pick(remap.axes[0]),
+                    pick(remap.axes[1]),
+                    pick(remap.axes[2]),
+                )
+            }
+            Mounting::Matrix(m) => rotate_i16(m, v, false),
+        }
+    }
+
+    /// 机体系原始数据 → 芯片系（逆变换）
+    pub fn to_chip(&self, v: (i16, i16, i16)) -> (i16, i16, i16) {
+        match self {
+            Mounting::Identity => v,
+            Mounting::Remap(remap) => {
+                let mut dst = [0i16; 3];
+                for (axis, val) in remap.axes.iter().zip([v.0, v.1, v.2]) {
+                    dst[axis.index()] = if axis.is_negative() {
+                        val.saturating_neg()
+                    } else {
+                        val
+                    };
+                }
+                (dst[0], dst[1], dst[2])
+            }
+            Mounting::Matrix(m) => rotate_i16(m, v, true),
+        }
+    }
+}
+
+/// 矩阵（或其转置）乘整型向量，四舍五入并饱和到 i16
+fn rotate_i16(m: &[[f32; 3]; 3], v: (i16, i16, i16), transpose: bool) -> (i16, i16, i16) {
+    let src = [v.0 as f32, v.1 as f32, v.2 as f32];
+    let mut out = [0i16; 3];
+    for (i, dst) in out.iter_mut().enumerate() {
+        let sum: f32 = (0..3)
+            .map(|k| if transpose { m[k][i] } else { m[i][k] } * src[k])
+            .sum();
+        *dst = libm::roundf(sum).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+    }
+    (out[0], out[1], out[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [MountAxis; 6] = [
+        MountAxis::PosX,
+        MountAxis::NegX,
+        MountAxis::PosY,
+        MountAxis::NegY,
+        MountAxis::PosZ,
+        MountAxis::NegZ,
+    ];
+
+    #[test]
+    fn test_24_axis_aligned_rotations() {
+        let mut count = 0;
+        for x in ALL {
+            for y in ALL {
+                if let Some(mounting) = Mounting::axes(x, y) {
+                    assert!(mounting.is_valid());
+                    let v = (100, -200, 300);
+                    assert_eq!(mounting.to_chip(mounting.to_body(v)), v);
+                    count += 1;
+                }
+            }
+        }
+        assert_eq!(count, 24);
+    }
+
+    #[test]
+    fn test_upside_down_and_matrix() {
+        let flipped = Mounting::axes(MountAxis::PosX, MountAxis::NegY).unwrap();
+        assert_eq!(flipped.to_body((1, 2, -16384)), (1, -2, 16384));
+        assert_eq!(flipped.to_body((0, i16::MIN, 0)), (0, i16::MAX, 0));
+
+        let as_matrix = Mounting::matrix(flipped.rotation_matrix());
+        assert!(as_matrix.is_valid());
+        assert_eq!(as_matrix.to_body((1, 2, -16384)), (1, -2, 16384));
+
+        let c = core::f32::consts::FRAC_1_SQRT_2;
+        let yaw45 = Mounting::matrix([[c, -c, 0.0], [c, c, 0.0], [0.0, 0.0, 1.0]]);
+        assert!(yaw45.is_valid());
+        assert_eq!(yaw45.to_body((1000, 0, 0)), (707, 707, 0));
+        assert_eq!(yaw45.to_chip((707, 707, 0)), (1000, 0, 0));
+
+        assert!(!Mounting::matrix([[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]).is_valid());
+        assert!(!Mounting::matrix([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]).is_valid());
+    }
+}
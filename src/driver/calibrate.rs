@@ -1,58 +1,388 @@
-use crate::Mpu6050;
+use super::ChipVariant;
+use super::convert::{GRAVITY, average_offset, temp_from_raw};
+use crate::calibration::{
+    AccelCalibration, CalibrationProfile, GyroCalibrationConfig, GyroCalibrationReport,
+    GyroStillness, GyroTempModel, RunningStats, SixPosition, SixPositionCalibrator, StillnessStep,
+    ThermalCalibrator,
+};
+use crate::error::{Mpu6050Error, Result};
+use crate::interface::Interface;
+use crate::{Mpu6050, RawSensorData};
+use embassy_time::{Instant, Timer};
 
-use embassy_time::Timer;
-use embedded_hal::digital::OutputPin;
-use embedded_hal_async::spi::SpiBus;
+/// 静止判定：加速度各轴标准差上限（g）
+pub(crate) const ACCEL_STILL_STD_G: f32 = 0.02;
+/// 重力对齐判定：均值模长偏离 1g 及非重力轴读数的上限（g）
+pub(crate) const ACCEL_GRAVITY_TOLERANCE_G: f32 = 0.1;
 
-impl<SPI, CS, T> Mpu6050<SPI, CS, T>
+/// 由静置采样统计计算软件加速度偏移（LSB）
+///
+/// 识别均值绝对值最大的轴为重力轴并按符号扣除 1g；
+/// 晃动或姿态不满足单轴竖直时返回 `None`
+pub(crate) fn accel_offset_from_stats(
+    stats: &RunningStats,
+    lsb_per_g: f32,
+) -> Option<(i16, i16, i16)> {
+    if stats
+        .std_dev()
+        .iter()
+        .any(|&s| s > ACCEL_STILL_STD_G * lsb_per_g)
+    {
+        return None;
+    }
+    let mean = stats.mean();
+    let axis = (0..3).max_by(|&a, &b| mean[a].abs().total_cmp(&mean[b].abs()))?;
+    let tolerance = ACCEL_GRAVITY_TOLERANCE_G * lsb_per_g;
+    if (mean[axis].abs() - lsb_per_g).abs() > tolerance
+        || (0..3).any(|i| i != axis && mean[i].abs() > tolerance)
+    {
+        return None;
+    }
+    let mut offset = mean;
+    offset[axis] -= libm::copysignf(lsb_per_g, mean[axis]);
+    let round = |v: f32| libm::roundf(v) as i16;
+    Some((round(offset[0]), round(offset[1]), round(offset[2])))
+}
+
+/// 三轴原始读数累加
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RawSum {
+    sum: (i32, i32, i32),
+    count: u16,
+}
+
+impl RawSum {
+    pub(crate) fn push(&mut self, raw: (i16, i16, i16)) {
+        self.sum.0 += raw.0 as i32;
+        self.sum.1 += raw.1 as i32;
+        self.sum.2 += raw.2 as i32;
+        self.count += 1;
+    }
+
+    /// 整数平均（LSB）
+    pub(crate) fn average(&self) -> (i16, i16, i16) {
+        average_offset(self.sum, self.count)
+    }
+
+    /// 加速度平均值（m/s²），`lsb_per_g` 为当前量程 1g 对应的 LSB
+    pub(crate) fn accel_mean(&self, lsb_per_g: f32) -> [f32; 3] {
+        let factor = GRAVITY / lsb_per_g / self.count as f32;
+        [
+            self.sum.0 as f32 * factor,
+            self.sum.1 as f32 * factor,
+            self.sum.2 as f32 * factor,
+        ]
+    }
+}
+
+/// 温度补偿标定单点的采样累加：陀螺仪（rad/s）统计与平均温度
+pub(crate) struct GyroTempSampler {
+    stats: RunningStats,
+    temp_sum: f32,
+    count: u16,
+    rad_per_lsb: f32,
+}
+
+impl GyroTempSampler {
+    pub(crate) fn new(lsb_per_rad: f32) -> Self {
+        Self {
+            stats: RunningStats::new(),
+            temp_sum: 0.0,
+            count: 0,
+            rad_per_lsb: 1.0 / lsb_per_rad,
+        }
+    }
+
+    pub(crate) fn push(&mut self, raw: &RawSensorData, chip: ChipVariant) {
+        let (x, y, z) = raw.gyro;
+        self.stats
+            .push([x as f32, y as f32, z as f32].map(|v| v * self.rad_per_lsb));
+        self.temp_sum += temp_from_raw::<f32>(raw.temp, chip);
+        self.count += 1;
+    }
+
+    /// 返回 `(平均温度 °C, 三轴零偏 rad/s)`；任一轴标准差超过 `max_std_dev`（rad/s）时返回 `None`
+    pub(crate) fn finish(&self, max_std_dev: f32) -> Option<(f32, [f32; 3])> {
+        if self.count == 0 || self.stats.std_dev().iter().any(|&s| s > max_std_dev) {
+            return None;
+        }
+        Some((self.temp_sum / self.count as f32, self.stats.mean()))
+    }
+}
+
+impl<DI, T> Mpu6050<DI, T>
 where
-    SPI: SpiBus<u8>,
-    CS: OutputPin,
+    DI: Interface,
     T: crate::numeric::NumericType,
 {
     /// 校准传感器
-    pub async fn calibrate_sensors(&mut self, cycle: u16) -> Result<(), SPI::Error> {
+    pub async fn calibrate_sensors(&mut self, cycle: u16) -> Result<(), DI::Error> {
         self.calibrate_accel(cycle).await?;
         self.calibrate_gyro(cycle).await?;
         Ok(())
     }
 
-    /// 校准加速度计
-    pub async fn calibrate_accel(&mut self, cycle: u16) -> Result<(), SPI::Error> {
-        let mut sum = (0i32, 0i32, 0i32);
-
+    /// 校准加速度计（软件偏移）
+    ///
+    /// 设备需静置且任一机体轴竖直（朝上或朝下均可）。按当前量程换算 1g 的 LSB，
+    /// 自动识别承受重力的轴及其符号，其余两轴视为零。
+    /// 新偏移给出完整修正，已设置的加速度计标定会重置为单位标定（后执行的标定生效）。
+    ///
+    /// `cycle` 为0、采集期间设备晃动或没有轴与重力对齐时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub async fn calibrate_accel(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        if cycle == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut stats = RunningStats::new();
         for _ in 0..cycle {
             let (x, y, z) = self.read_accel_raw().await?;
-            sum.0 += x as i32;
-            sum.1 += y as i32;
-            sum.2 += z as i32;
+            stats.push([x as f32, y as f32, z as f32]);
             Timer::after_micros(500).await;
         }
-        let avg = (
-            (sum.0 / cycle as i32) as i16,
-            (sum.1 / cycle as i32) as i16,
-            ((sum.2 / cycle as i32) - 16384) as i16, // 减去重力加速度
-        );
-        self.accel_offset = avg;
+        let lsb_per_g = self.config.accel_scale.get_scale_factor();
+        let offset =
+            accel_offset_from_stats(&stats, lsb_per_g).ok_or(Mpu6050Error::CalibrationFailed)?;
+        self.accel_offset = offset;
+        self.accel_calibration = AccelCalibration::identity();
         Ok(())
     }
 
-    /// 校准陀螺仪
-    pub async fn calibrate_gyro(&mut self, cycle: u16) -> Result<(), SPI::Error> {
-        let mut sum = (0i32, 0i32, 0i32);
+    /// 校准陀螺仪（软件偏移）
+    ///
+    /// 新偏移给出完整零偏，已设置的温度模型会被清除（后执行的标定生效）。
+    /// `cycle` 为0时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub async fn calibrate_gyro(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        if cycle == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut sum = RawSum::default();
         for _ in 0..cycle {
-            let (x, y, z) = self.read_gyro_raw().await?;
-            sum.0 += x as i32;
-            sum.1 += y as i32;
-            sum.2 += z as i32;
+            sum.push(self.read_gyro_raw().await?);
             Timer::after_micros(500).await;
         }
-        let avg = (
-            (sum.0 / cycle as i32) as i16,
-            (sum.1 / cycle as i32) as i16,
-            (sum.2 / cycle as i32) as i16,
-        );
-        self.gyro_offset = avg;
+        self.store_gyro_offset(sum.average());
         Ok(())
     }
+
+    /// 带静止检测的陀螺仪校准，成功后写入软件偏移并返回噪声等质量指标
+    ///
+    /// 与 [`calibrate_gyro`](Self::calibrate_gyro) 相同，成功后清除温度模型。
+    ///
+    /// 在线计算各轴方差，单样本突变或整轮标准差超过 `max_std_dev` 视为运动：
+    /// - `timeout_ms` 为 `None` 时立即返回 [`Mpu6050Error::CalibrationFailed`]
+    /// - 否则丢弃本轮重新采集，自开始起超过 `timeout_ms` 仍未静止则返回 [`Mpu6050Error::Timeout`]
+    ///
+    /// `samples` 为0时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub async fn calibrate_gyro_still(
+        &mut self,
+        config: GyroCalibrationConfig,
+    ) -> Result<GyroCalibrationReport, DI::Error> {
+        if config.samples == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let lsb_per_rad = self.config.gyro_scale.get_scale_factor() * crate::RAD2DEG;
+        let mut still = GyroStillness::new(config, lsb_per_rad);
+        let start = Instant::now();
+        loop {
+            let raw = self.read_gyro_raw().await?;
+            match still.push(raw) {
+                StillnessStep::Collecting => {}
+                StillnessStep::Done(report) => {
+                    self.store_gyro_offset(report.offset);
+                    return Ok(report);
+                }
+                StillnessStep::Moved => {
+                    let Some(timeout_ms) = config.timeout_ms else {
+                        return Err(Mpu6050Error::CalibrationFailed);
+                    };
+                    if start.elapsed().as_millis() > timeout_ms as u64 {
+                        return Err(Mpu6050Error::Timeout);
+                    }
+                    still.restart();
+                }
+            }
+            Timer::after_micros(500).await;
+        }
+    }
+
+    /// 设置加速度计标定（如六面法结果），同时清零软件加速度偏移
+    pub fn set_accel_calibration(&mut self, calibration: AccelCalibration) {
+        self.accel_calibration = calibration;
+        self.accel_offset = (0, 0, 0);
+    }
+
+    /// 当前加速度计标定
+    pub fn accel_calibration(&self) -> AccelCalibration {
+        self.accel_calibration
+    }
+
+    /// 设置陀螺仪零偏温度模型；设置为 `Some` 时模型给出完整零偏，软件陀螺仪偏移清零
+    ///
+    /// 模型在 `read_gyro`、`read_all` 及基于它们的姿态更新中自动生效
+    pub fn set_gyro_temp_model(&mut self, model: Option<GyroTempModel>) {
+        if model.is_some() {
+            self.gyro_offset = (0, 0, 0);
+        }
+        self.gyro_temp_model = model;
+    }
+
+    /// 当前陀螺仪零偏温度模型
+    pub fn gyro_temp_model(&self) -> Option<GyroTempModel> {
+        self.gyro_temp_model
+    }
+
+    /// 写入软件陀螺仪偏移；偏移由未补偿数据算出，温度模型随之失效
+    fn store_gyro_offset(&mut self, offset: (i16, i16, i16)) {
+        self.gyro_offset = offset;
+        self.gyro_temp_model = None;
+    }
+
+    /// 温度补偿标定：静置采集当前温度下的陀螺仪零偏并记录到 `calibrator`
+    ///
+    /// 在不同温度（如升温过程中）多次调用后用 [`ThermalCalibrator::fit`] 拟合，
+    /// 再通过 [`set_gyro_temp_model`](Self::set_gyro_temp_model) 应用。
+    /// 返回 `(温度 °C, 三轴零偏 rad/s)`。
+    ///
+    /// 采集 `config.samples` 个样本，任一轴标准差超过 `config.max_std_dev` 视为晃动；
+    /// 不做重新采集，`timeout_ms` 不使用。
+    /// 样本数为0、采集期间设备晃动或记录已满时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub async fn record_gyro_temp_point(
+        &mut self,
+        calibrator: &mut ThermalCalibrator,
+        config: &GyroCalibrationConfig,
+    ) -> Result<(f32, [f32; 3]), DI::Error> {
+        if config.samples == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let lsb_per_rad = self.config.gyro_scale.get_scale_factor() * crate::RAD2DEG;
+        let mut sampler = GyroTempSampler::new(lsb_per_rad);
+        for _ in 0..config.samples {
+            sampler.push(&self.read_all_raw().await?, self.chip);
+            Timer::after_micros(500).await;
+        }
+        let (temp, bias) = sampler
+            .finish(config.max_std_dev)
+            .ok_or(Mpu6050Error::CalibrationFailed)?;
+        if !calibrator.add_point(temp, bias) {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        Ok((temp, bias))
+    }
+
+    /// 导出当前标定（软件偏移、加速度计标定、温度模型及当前量程）为档案
+    pub fn calibration_profile(&self) -> CalibrationProfile {
+        CalibrationProfile {
+            accel_scale: self.config.accel_scale,
+            gyro_scale: self.config.gyro_scale,
+            accel_offset: self.accel_offset,
+            gyro_offset: self.gyro_offset,
+            accel_calibration: self.accel_calibration,
+            gyro_temp_model: self.gyro_temp_model,
+        }
+    }
+
+    /// 应用标定档案，软件偏移按当前配置量程换算
+    pub fn apply_calibration_profile(&mut self, profile: &CalibrationProfile) {
+        (self.accel_offset, self.gyro_offset) =
+            profile.offsets_for(self.config.accel_scale, self.config.gyro_scale);
+        self.accel_calibration = profile.accel_calibration;
+        self.gyro_temp_model = profile.gyro_temp_model;
+    }
+
+    /// 六面法标定：采集一个姿态的平均读数并记录到 `calibrator`
+    ///
+    /// 读数未扣除软件偏移与现有标定。`samples` 为0或读数与 `position` 不符
+    /// （放置方向错误）时返回 [`Mpu6050Error::CalibrationFailed`]。
+    /// 六个姿态采集完成后用 [`SixPositionCalibrator::solve`] 解算，
+    /// 再通过 [`set_accel_calibration`](Self::set_accel_calibration) 应用
+    pub async fn collect_accel_position(
+        &mut self,
+        calibrator: &mut SixPositionCalibrator,
+        position: SixPosition,
+        samples: u16,
+    ) -> Result<[f32; 3], DI::Error> {
+        if samples == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut sum = RawSum::default();
+        for _ in 0..samples {
+            sum.push(self.read_accel_raw().await?);
+            Timer::after_micros(500).await;
+        }
+        let mean = sum.accel_mean(self.config.accel_scale.get_scale_factor());
+        if !calibrator.record(position, mean) {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        Ok(mean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::register::{ACCEL_SCALE_2G, ACCEL_SCALE_8G};
+
+    fn stats_of(samples: &[[f32; 3]]) -> RunningStats {
+        let mut stats = RunningStats::new();
+        for &sample in samples {
+            stats.push(sample);
+        }
+        stats
+    }
+
+    #[test]
+    fn test_accel_offset_respects_range_and_orientation() {
+        // ±8g 下 1g = 4096 LSB，z 轴朝上
+        let stats = stats_of(&[[10.0, -5.0, 4100.0], [12.0, -3.0, 4102.0]]);
+        assert_eq!(
+            accel_offset_from_stats(&stats, ACCEL_SCALE_8G),
+            Some((11, -4, 5))
+        );
+
+        // ±2g 下 y 轴朝下
+        let stats = stats_of(&[[20.0, -16300.0, 40.0], [20.0, -16302.0, 40.0]]);
+        assert_eq!(
+            accel_offset_from_stats(&stats, ACCEL_SCALE_2G),
+            Some((20, 83, 40))
+        );
+    }
+
+    #[test]
+    fn test_sample_accumulators() {
+        let mut sum = RawSum::default();
+        sum.push((100, -200, 16384));
+        sum.push((102, -202, 16386));
+        assert_eq!(sum.average(), (101, -201, 16385));
+        let mean = sum.accel_mean(ACCEL_SCALE_2G);
+        assert!((mean[2] - 16385.0 * GRAVITY / ACCEL_SCALE_2G).abs() < 1e-4);
+
+        let lsb_per_rad = 131.0 * crate::RAD2DEG;
+        let mut sampler = GyroTempSampler::new(lsb_per_rad);
+        assert!(sampler.finish(0.01).is_none());
+        for gx in [130, 132] {
+            let raw = RawSensorData {
+                gyro: (gx, 0, 0),
+                ..RawSensorData::default()
+            };
+            sampler.push(&raw, ChipVariant::Mpu6050);
+        }
+        let (temp, bias) = sampler.finish(0.01).unwrap();
+        assert!((temp - 36.53).abs() < 1e-2);
+        assert!((bias[0] - crate::DEG2RAD).abs() < 1e-5);
+        assert!(sampler.finish(1e-4).is_none());
+    }
+
+    #[test]
+    fn test_accel_offset_rejects_motion_and_tilt() {
+        let moving = stats_of(&[
+            [0.0, 0.0, 16384.0],
+            [2000.0, 0.0, 15000.0],
+            [0.0, 0.0, 16384.0],
+        ]);
+        assert_eq!(accel_offset_from_stats(&moving, ACCEL_SCALE_2G), None);
+
+        // 倾斜约 30°
+        let tilted = stats_of(&[[8192.0, 0.0, 14189.0], [8192.0, 0.0, 14189.0]]);
+        assert_eq!(accel_offset_from_stats(&tilted, ACCEL_SCALE_2G), None);
+    }
 }
@@ -0,0 +1,104 @@
+//! MPU6050 系列芯片识别
+
+use crate::register::*;
+
+/// 检测到的芯片型号
+///
+/// 同系列芯片寄存器布局基本兼容，驱动根据型号调整温度换算、
+/// 加速度计DLPF等存在差异的部分
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ChipVariant {
+    /// MPU6050 / MPU6000（WHO_AM_I 均为 0x68，寄存器完全相同，无法区分）
+    #[default]
+    Mpu6050,
+    /// MPU6500（0x70）
+    Mpu6500,
+    /// MPU9250（0x71）
+    Mpu9250,
+    /// ICM-20602（0x12）
+    Icm20602,
+}
+
+impl ChipVariant {
+    /// 根据 WHO_AM_I 寄存器值识别芯片，未知ID返回 `None`
+    pub fn from_who_am_i(id: u8) -> Option<Self> {
+        match id {
+            WHO_AM_I_VALUE => Some(ChipVariant::Mpu6050),
+            WHO_AM_I_MPU6500 => Some(ChipVariant::Mpu6500),
+            WHO_AM_I_MPU9250 => Some(ChipVariant::Mpu9250),
+            WHO_AM_I_ICM20602 => Some(ChipVariant::Icm20602),
+            _ => None,
+        }
+    }
+
+    /// 该型号的 WHO_AM_I 值
+    pub fn who_am_i(&self) -> u8 {
+        match self {
+            ChipVariant::Mpu6050 => WHO_AM_I_VALUE,
+            ChipVariant::Mpu6500 => WHO_AM_I_MPU6500,
+            ChipVariant::Mpu9250 => WHO_AM_I_MPU9250,
+            ChipVariant::Icm20602 => WHO_AM_I_ICM20602,
+        }
+    }
+
+    /// 是否为MPU6500系列（拥有独立的 ACCEL_CONFIG2 寄存器）
+    pub fn is_mpu6500_family(&self) -> bool {
+        !matches!(self, ChipVariant::Mpu6050)
+    }
+
+    /// 加速度计偏移寄存器（X/Y/Z高字节地址）
+    pub fn accel_offset_registers(&self) -> [u8; 3] {
+        match self {
+            ChipVariant::Mpu6050 => [XA_OFFS_USRH, YA_OFFS_USRH, ZA_OFFS_USRH],
+            _ => [XA_OFFSET_H, YA_OFFSET_H, ZA_OFFSET_H],
+        }
+    }
+
+    /// FIFO 容量（字节），FIFO 计数达到该值即视为已溢出
+    pub fn fifo_size(&self) -> usize {
+        match self {
+            ChipVariant::Mpu6050 => 1024,
+            ChipVariant::Mpu6500 | ChipVariant::Mpu9250 => 512,
+            ChipVariant::Icm20602 => 1008,
+        }
+    }
+
+    /// 温度转换比例与偏移（LSB/°C, °C）
+    pub fn temp_scale_offset(&self) -> (f32, f32) {
+        match self {
+            ChipVariant::Mpu6050 => (MPU6050_TEMP_SCALE, MPU6050_TEMP_OFFSET),
+            ChipVariant::Mpu6500 | ChipVariant::Mpu9250 => (TEMP_SCALE, TEMP_OFFSET),
+            ChipVariant::Icm20602 => (ICM20602_TEMP_SCALE, ICM20602_TEMP_OFFSET),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_who_am_i() {
+        assert_eq!(ChipVariant::from_who_am_i(0x68), Some(ChipVariant::Mpu6050));
+        assert_eq!(ChipVariant::from_who_am_i(0x70), Some(ChipVariant::Mpu6500));
+        assert_eq!(ChipVariant::from_who_am_i(0x71), Some(ChipVariant::Mpu9250));
+        assert_eq!(
+            ChipVariant::from_who_am_i(0x12),
+            Some(ChipVariant::Icm20602)
+        );
+        assert_eq!(ChipVariant::from_who_am_i(0x00), None);
+        assert_eq!(ChipVariant::from_who_am_i(0xFF), None);
+    }
+
+    #[test]
+    fn test_who_am_i_roundtrip() {
+        for chip in [
+            ChipVariant::Mpu6050,
+            ChipVariant::Mpu6500,
+            ChipVariant::Mpu9250,
+            ChipVariant::Icm20602,
+        ] {
+            assert_eq!(ChipVariant::from_who_am_i(chip.who_am_i()), Some(chip));
+        }
+    }
+}
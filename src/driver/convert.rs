@@ -0,0 +1,136 @@
+//! 原始数据解码与物理量转换
+//!
+//! 异步与阻塞驱动共用同一套实现
+
+use super::ChipVariant;
+use crate::calibration::{AccelCalibration, GyroTempModel};
+use crate::config::{AccelScale, GyroScale, Mounting, Mpu6050Config};
+use crate::numeric::NumericType;
+use crate::{RawSensorData, SensorData};
+
+/// 重力加速度（m/s²）
+pub(crate) const GRAVITY: f32 = 9.81;
+
+/// 解码三轴大端数据
+pub(crate) fn decode_i16x3(buf: &[u8]) -> (i16, i16, i16) {
+    (
+        i16::from_be_bytes([buf[0], buf[1]]),
+        i16::from_be_bytes([buf[2], buf[3]]),
+        i16::from_be_bytes([buf[4], buf[5]]),
+    )
+}
+
+/// ACCEL_XOUT_H..GYRO_ZOUT_L 突发读取长度
+pub(crate) const SENSOR_BURST_LEN: usize = 14;
+
+/// 解码 ACCEL_XOUT_H 起始的14字节突发数据
+pub(crate) fn decode_sensor_burst(buf: &[u8; SENSOR_BURST_LEN]) -> RawSensorData {
+    RawSensorData {
+        accel: decode_i16x3(&buf[0..6]),
+        temp: i16::from_be_bytes([buf[6], buf[7]]),
+        gyro: decode_i16x3(&buf[8..14]),
+    }
+}
+
+/// 芯片系原始数据转换到机体系（温度不变）
+pub(crate) fn mount_raw(raw: RawSensorData, mounting: &Mounting) -> RawSensorData {
+    RawSensorData {
+        accel: mounting.to_body(raw.accel),
+        gyro: mounting.to_body(raw.gyro),
+        temp: raw.temp,
+    }
+}
+
+/// 原始数据快照转换为物理量
+pub(crate) fn sensor_data_from_raw<T: NumericType>(
+    raw: &RawSensorData,
+    accel_offset: (i16, i16, i16),
+    gyro_offset: (i16, i16, i16),
+    accel_calibration: &AccelCalibration,
+    gyro_temp_model: Option<&GyroTempModel>,
+    config: &Mpu6050Config,
+    chip: ChipVariant,
+) -> SensorData<T> {
+    let temp = temp_from_raw(raw.temp, chip);
+    let gyro = gyro_from_raw(raw.gyro, gyro_offset, config.gyro_scale);
+    SensorData {
+        accel: accel_calibration.apply(accel_from_raw(raw.accel, accel_offset, config.accel_scale)),
+        gyro: gyro_temp_model.map_or(gyro, |model| compensate_gyro(gyro, model, temp)),
+        temp,
+    }
+}
+
+/// TEMP_OUT_H..GYRO_ZOUT_L 突发读取长度
+pub(crate) const TEMP_GYRO_BURST_LEN: usize = 8;
+
+/// 按温度模型扣除陀螺仪零偏（rad/s）
+pub(crate) fn compensate_gyro<T: NumericType>(
+    gyro: (T, T, T),
+    model: &GyroTempModel,
+    temp: T,
+) -> (T, T, T) {
+    let bias = model.bias_at(temp.to_f32());
+    (
+        gyro.0 - T::from_f32(bias[0]),
+        gyro.1 - T::from_f32(bias[1]),
+        gyro.2 - T::from_f32(bias[2]),
+    )
+}
+
+/// 原始加速度计数据转换为 m/s²
+///
+/// 在 i32 中扣除偏移，饱和读数（±32767）与反号偏移相减不会溢出
+pub(crate) fn accel_from_raw<T: NumericType>(
+    raw: (i16, i16, i16),
+    offset: (i16, i16, i16),
+    scale: AccelScale,
+) -> (T, T, T) {
+    let factor = GRAVITY / scale.get_scale_factor();
+    (
+        T::from_f32((raw.0 as i32 - offset.0 as i32) as f32 * factor),
+        T::from_f32((raw.1 as i32 - offset.1 as i32) as f32 * factor),
+        T::from_f32((raw.2 as i32 - offset.2 as i32) as f32 * factor),
+    )
+}
+
+/// 原始陀螺仪数据转换为 rad/s
+pub(crate) fn gyro_from_raw<T: NumericType>(
+    raw: (i16, i16, i16),
+    offset: (i16, i16, i16),
+    scale: GyroScale,
+) -> (T, T, T) {
+    let factor = core::f32::consts::PI / (180.0 * scale.get_scale_factor());
+    (
+        T::from_f32((raw.0 as i32 - offset.0 as i32) as f32 * factor),
+        T::from_f32((raw.1 as i32 - offset.1 as i32) as f32 * factor),
+        T::from_f32((raw.2 as i32 - offset.2 as i32) as f32 * factor),
+    )
+}
+
+/// 原始温度数据转换为摄氏度
+pub(crate) fn temp_from_raw<T: NumericType>(raw: i16, chip: ChipVariant) -> T {
+    let (scale, offset) = chip.temp_scale_offset();
+    T::from_f32((raw as f32) / scale + offset)
+}
+
+/// 累加和求平均，得到三轴偏移
+pub(crate) fn average_offset(sum: (i32, i32, i32), cycle: u16) -> (i16, i16, i16) {
+    let n = cycle as i32;
+    ((sum.0 / n) as i16, (sum.1 / n) as i16, (sum.2 / n) as i16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturated_raw_minus_offset_does_not_overflow() {
+        let (ax, _, _): (f32, f32, f32) =
+            accel_from_raw((i16::MAX, 0, 0), (-100, 0, 0), AccelScale::Scale2G);
+        assert!((ax - 32867.0 * GRAVITY / 16384.0).abs() < 1e-3);
+
+        let (gx, _, _): (f32, f32, f32) =
+            gyro_from_raw((i16::MIN, 0, 0), (100, 0, 0), GyroScale::Scale250);
+        assert!((gx + 32868.0 / 131.0 * crate::DEG2RAD).abs() < 1e-3);
+    }
+}
@@ -1,48 +1,492 @@
-use crate::Mpu6050;
+use super::convert::{mount_raw, sensor_data_from_raw};
+use crate::config::FifoChannels;
+use crate::error::Result;
+use crate::interface::Interface;
 use crate::register::*;
-use embedded_hal::digital::OutputPin;
-use embedded_hal_async::spi::SpiBus;
+use crate::{Mpu6050, RawSensorData, SensorData};
 
-impl<SPI, CS, T> Mpu6050<SPI, CS, T>
+/// 单帧最大长度：加速度6 + 温度2 + 陀螺仪6
+pub(crate) const FIFO_MAX_FRAME_LEN: usize = 14;
+/// 每次突发读取 FIFO 的最大字节数
+pub(crate) const FIFO_CHUNK_LEN: usize = 64;
+
+/// 解码一帧 FIFO 数据（芯片坐标系）
+///
+/// 帧内按寄存器地址顺序排列：ACCEL → TEMP → GYRO_X → GYRO_Y → GYRO_Z。
+/// 未启用的通道填0，温度未进入 FIFO 时使用 `fallback_temp`
+pub(crate) fn decode_fifo_frame(
+    frame: &[u8],
+    channels: FifoChannels,
+    fallback_temp: i16,
+) -> RawSensorData {
+    let mut pos = 0;
+    let mut next = || {
+        let v = i16::from_be_bytes([frame[pos], frame[pos + 1]]);
+        pos += 2;
+        v
+    };
+    let mut raw = RawSensorData {
+        temp: fallback_temp,
+        ..RawSensorData::default()
+    };
+    if channels.contains(FifoChannels::ACCEL) {
+        raw.accel = (next(), next(), next());
+    }
+    if channels.contains(FifoChannels::TEMP) {
+        raw.temp = next();
+    }
+    if channels.contains(FifoChannels::GYRO_X) {
+        raw.gyro.0 = next();
+    }
+    if channels.contains(FifoChannels::GYRO_Y) {
+        raw.gyro.1 = next();
+    }
+    if channels.contains(FifoChannels::GYRO_Z) {
+        raw.gyro.2 = next();
+    }
+    raw
+}
+
+/// FIFO 溢出统计，见 [`Mpu6050::fifo_status`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FifoStatus {
+    /// 检测到并已恢复的溢出次数
+    pub overflow_count: u32,
+    /// 溢出恢复时丢弃的帧数（下限：被硬件覆盖的旧帧无法计数）
+    pub dropped_frames: u32,
+}
+
+impl FifoStatus {
+    /// 记录一次溢出：FIFO 中剩余的 `fifo_bytes` 与缓存的 `pending_bytes` 一并丢弃
+    pub(crate) fn record_overflow(
+        &mut self,
+        fifo_bytes: usize,
+        pending_bytes: usize,
+        frame_len: usize,
+    ) {
+        let dropped = (fifo_bytes + pending_bytes).div_ceil(frame_len);
+        self.overflow_count = self.overflow_count.saturating_add(1);
+        self.dropped_frames = self.dropped_frames.saturating_add(dropped as u32);
+    }
+}
+
+/// [`FifoPending::plan`] 对一次 FIFO 读取的判定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FifoRead {
+    /// 已溢出：丢弃数据已计入统计、缓存已清空，需要复位 FIFO
+    Overflow,
+    /// 从 FIFO 读取的字节数（可能为0）
+    Bytes(usize),
+}
+
+/// 跨调用保留的不完整 FIFO 帧
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FifoPending {
+    buf: [u8; FIFO_MAX_FRAME_LEN],
+    len: usize,
+    /// 缓存字节所属的帧长度
+    frame_len: usize,
+}
+
+impl FifoPending {
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 帧长度变化时丢弃按旧格式缓存的字节
+    fn set_frame_len(&mut self, frame_len: usize) {
+        if self.frame_len != frame_len {
+            self.clear();
+            self.frame_len = frame_len;
+        }
+    }
+
+    /// 追加一个字节，凑满 `frame_len` 时返回完整帧并清空
+    pub(crate) fn push(&mut self, byte: u8, frame_len: usize) -> Option<[u8; FIFO_MAX_FRAME_LEN]> {
+        self.set_frame_len(frame_len);
+        self.buf[self.len] = byte;
+        self.len += 1;
+        if self.len < frame_len {
+            return None;
+        }
+        self.len = 0;
+        Some(self.buf)
+    }
+
+    /// 由 INT_STATUS 与 FIFO 计数决定本次读取的字节数，读取量最多凑满 `max_frames` 帧
+    ///
+    /// 帧长度与缓存数据不一致（通道配置已修改）时先丢弃缓存。
+    /// FIFO_OFLOW 置位或计数达到 `fifo_size` 时视为溢出：FIFO 与缓存中的数据计入 `status`，
+    /// 缓存清空
+    pub(crate) fn plan(
+        &mut self,
+        status: &mut FifoStatus,
+        int_status: u8,
+        available: usize,
+        fifo_size: usize,
+        frame_len: usize,
+        max_frames: usize,
+    ) -> FifoRead {
+        self.set_frame_len(frame_len);
+        if int_status & INT_STATUS_FIFO_OFLOW != 0 || available >= fifo_size {
+            status.record_overflow(available, self.len(), frame_len);
+            self.clear();
+            return FifoRead::Overflow;
+        }
+        FifoRead::Bytes(available.min((max_frames * frame_len).saturating_sub(self.len())))
+    }
+
+    /// 追加一块 FIFO 数据，凑满的帧解码（芯片坐标系）后经 `convert` 依次写入 `out`，
+    /// 返回写入的帧数
+    pub(crate) fn drain<T: crate::numeric::NumericType>(
+        &mut self,
+        chunk: &[u8],
+        channels: FifoChannels,
+        fallback_temp: i16,
+        out: &mut [SensorData<T>],
+        convert: impl Fn(RawSensorData) -> SensorData<T>,
+    ) -> usize {
+        let frame_len = channels.frame_len();
+        let mut count = 0;
+        for &byte in chunk {
+            if let Some(frame) = self.push(byte, frame_len) {
+                out[count] = convert(decode_fifo_frame(
+                    &frame[..frame_len],
+                    channels,
+                    fallback_temp,
+                ));
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+impl<DI, T> Mpu6050<DI, T>
 where
-    SPI: SpiBus<u8>,
-    CS: OutputPin,
+    DI: Interface,
     T: crate::numeric::NumericType,
 {
-    /// 启用FIFO
-    pub async fn enable_fifo(&mut self) -> Result<(), SPI::Error> {
-        self.write_register(USER_CTRL, 0x40).await?; // 启用FIFO
-        self.write_register(FIFO_EN, 0x78).await?; // 启用加速度计和陀螺仪数据
+    /// 启用FIFO，写入 `config.fifo_channels` 选择的通道
+    ///
+    /// USER_CTRL 按读-改-写更新，保留 I2C_MST_EN、I2C_IF_DIS 等其他位
+    pub async fn enable_fifo(&mut self) -> Result<(), DI::Error> {
+        self.fifo_pending.clear();
+        let current = self.read_register(USER_CTRL).await?;
+        self.write_register(USER_CTRL, current | USER_CTRL_FIFO_EN)
+            .await?;
+        self.write_register(FIFO_EN, self.config.fifo_channels.bits())
+            .await?;
         Ok(())
     }
 
+    /// 清空并重新启用FIFO，之后的数据从帧边界开始
+    pub async fn reset_fifo(&mut self) -> Result<(), DI::Error> {
+        self.write_register(FIFO_EN, 0x00).await?;
+        // FIFO_RESET 需在 FIFO 使能位为0时写入
+        let current = self.read_register(USER_CTRL).await?;
+        self.write_register(
+            USER_CTRL,
+            (current & !USER_CTRL_FIFO_EN) | USER_CTRL_FIFO_RESET,
+        )
+        .await?;
+        self.enable_fifo().await
+    }
+
+    /// FIFO 溢出统计
+    pub fn fifo_status(&self) -> FifoStatus {
+        self.fifo_status
+    }
+
+    /// 清零 FIFO 溢出统计
+    pub fn clear_fifo_status(&mut self) {
+        self.fifo_status = FifoStatus::default();
+    }
+
     /// 禁用FIFO
-    pub async fn disable_fifo(&mut self) -> Result<(), SPI::Error> {
+    pub async fn disable_fifo(&mut self) -> Result<(), DI::Error> {
         self.write_register(FIFO_EN, 0x00).await?;
-        self.write_register(USER_CTRL, 0x00).await?;
+        let current = self.read_register(USER_CTRL).await?;
+        self.write_register(USER_CTRL, current & !USER_CTRL_FIFO_EN)
+            .await?;
+        self.fifo_pending.clear();
         Ok(())
     }
 
     /// 读取FIFO计数
-    pub async fn read_fifo_count(&mut self) -> Result<u16, SPI::Error> {
-        let mut buf = [FIFO_COUNTH | 0x80, 0, 0];
-        self.cs.set_low().ok();
-        self.spi.transfer_in_place(&mut buf).await?;
-        self.cs.set_high().ok();
-        let count = ((buf[1] as u16) << 8) | (buf[2] as u16);
+    pub async fn read_fifo_count(&mut self) -> Result<u16, DI::Error> {
+        let mut buf = [0u8; 2];
+        self.read_registers(FIFO_COUNTH, &mut buf).await?;
+        Ok(u16::from_be_bytes(buf))
+    }
+
+    /// 读取FIFO数据，填满 `data`
+    pub async fn read_fifo_data(&mut self, data: &mut [u8]) -> Result<(), DI::Error> {
+        self.read_registers(FIFO_R_W, data).await
+    }
+
+    /// 读取并解码 FIFO 中的完整帧，返回写入 `out` 的帧数
+    ///
+    /// 按 `config.fifo_channels` 决定的帧格式分块读取（每块最多 64 字节），最多取满 `out`；
+    /// 帧数据经安装方向、偏移、标定和量程换算，与 [`read_all`](Self::read_all) 一致。
+    /// 不足一帧的尾部字节保留到下次调用。温度未进入 FIFO 时各帧使用本次读取时的温度。
+    ///
+    /// 读取前检查 INT_STATUS 的 FIFO_OFLOW 标志（会同时清除其他中断标志）与 FIFO 计数，
+    /// 发现溢出时帧边界已不可信：丢弃 FIFO 与缓存中的全部数据，复位 FIFO 后返回 0，
+    /// 丢弃帧数计入 [`fifo_status`](Self::fifo_status)。
+    pub async fn read_fifo_frames(
+        &mut self,
+        out: &mut [SensorData<T>],
+    ) -> Result<usize, DI::Error> {
+        let channels = self.config.fifo_channels;
+        let frame_len = channels.frame_len();
+        if out.is_empty() || frame_len == 0 {
+            return Ok(0);
+        }
+        let int_status = self.read_register(INT_STATUS).await?;
+        let available = self.read_fifo_count().await? as usize;
+        let mut remaining = match self.fifo_pending.plan(
+            &mut self.fifo_status,
+            int_status,
+            available,
+            self.chip.fifo_size(),
+            frame_len,
+            out.len(),
+        ) {
+            FifoRead::Overflow => {
+                self.reset_fifo().await?;
+                return Ok(0);
+            }
+            FifoRead::Bytes(0) => return Ok(0),
+            FifoRead::Bytes(n) => n,
+        };
+        let fallback_temp = if !channels.contains(FifoChannels::TEMP) {
+            let mut buf = [0u8; 2];
+            self.read_registers(TEMP_OUT_H, &mut buf).await?;
+            i16::from_be_bytes(buf)
+        } else {
+            0
+        };
+
+        let mut count = 0;
+        let mut chunk = [0u8; FIFO_CHUNK_LEN];
+        while remaining > 0 {
+            let len = remaining.min(FIFO_CHUNK_LEN);
+            self.read_registers(FIFO_R_W, &mut chunk[..len]).await?;
+            remaining -= len;
+            count += self.fifo_pending.drain(
+                &chunk[..len],
+                channels,
+                fallback_temp,
+                &mut out[count..],
+                |raw| {
+                    sensor_data_from_raw(
+                        &mount_raw(raw, &self.config.mounting),
+                        self.accel_offset,
+                        self.gyro_offset,
+                        &self.accel_calibration,
+                        self.gyro_temp_model.as_ref(),
+                        &self.config,
+                        self.chip,
+                    )
+                },
+            );
+        }
         Ok(count)
     }
+}
 
-    /// 读取FIFO数据（固定大小缓冲区）
-    pub async fn read_fifo_data(&mut self, data: &mut [u8]) -> Result<(), SPI::Error> {
-        let mut buf = [0u8; 64]; // 固定大小缓冲区
-        buf[0] = FIFO_R_W | 0x80;
-        self.cs.set_low().ok();
-        self.spi
-            .transfer_in_place(&mut buf[..data.len() + 1])
-            .await?;
-        self.cs.set_high().ok();
-        data.copy_from_slice(&buf[1..data.len() + 1]);
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_layout() {
+        let frame = [0, 1, 0, 2, 0, 3, 0x12, 0x34, 0, 4, 0, 5, 0, 6];
+        let raw = decode_fifo_frame(&frame, FifoChannels::ALL, 0);
+        assert_eq!(raw.accel, (1, 2, 3));
+        assert_eq!(raw.temp, 0x1234);
+        assert_eq!(raw.gyro, (4, 5, 6));
+
+        let channels = FifoChannels::GYRO_X | FifoChannels::GYRO_Z;
+        let raw = decode_fifo_frame(&[0xFF, 0xFF, 0, 7], channels, -5);
+        assert_eq!(raw.gyro, (-1, 0, 7));
+        assert_eq!(raw.temp, -5);
+    }
+
+    #[test]
+    fn test_read_fifo_frames_buffers_partial_frame() {
+        use crate::config::Mpu6050Config;
+        use crate::interface::mock::{MockInterface, block_on};
+
+        let mut iface = MockInterface::new();
+        // 两帧半：加速度 +1g z，陀螺仪 x 131 LSB
+        let frame = [0, 0, 0, 0, 0x40, 0, 0, 0x83, 0, 0, 0, 0];
+        iface.push_fifo(&frame);
+        iface.push_fifo(&frame);
+        iface.push_fifo(&frame[..5]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+
+        let mut out = [SensorData::default(); 4];
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 2);
+        assert!((out[1].accel.2 - 9.81).abs() < 1e-3);
+        assert!((out[1].gyro.0 - crate::DEG2RAD).abs() < 1e-4);
+        assert_eq!(mpu.fifo_pending.len(), 5);
+
+        mpu.interface.push_fifo(&frame[5..]);
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 1);
+        assert!((out[0].accel.2 - 9.81).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_read_fifo_frames_custom_channels() {
+        use crate::config::Mpu6050Config;
+        use crate::interface::mock::{MockInterface, block_on};
+
+        let config = Mpu6050Config {
+            fifo_channels: FifoChannels::TEMP | FifoChannels::GYRO_Y,
+            ..Mpu6050Config::default()
+        };
+        let mut iface = MockInterface::new();
+        // 温度 0（36.53℃），陀螺仪 y 131 LSB
+        iface.push_fifo(&[0, 0, 0, 0x83, 0, 0, 0, 0x83]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, config);
+
+        block_on(mpu.enable_fifo()).unwrap();
+        assert_eq!(mpu.interface.regs[FIFO_EN as usize], 0xA0);
+
+        let mut out = [SensorData::default(); 4];
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 2);
+        assert_eq!(out[1].accel, (0.0, 0.0, 0.0));
+        assert!((out[1].gyro.1 - crate::DEG2RAD).abs() < 1e-4);
+        assert!((out[1].temp - 36.53).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_read_fifo_frames_recovers_from_overflow() {
+        use crate::config::Mpu6050Config;
+        use crate::interface::mock::{MockInterface, block_on};
+
+        let mut iface = MockInterface::new();
+        let frame = [0, 0, 0, 0, 0x40, 0, 0, 0x83, 0, 0, 0, 0];
+        iface.push_fifo(&frame[..7]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+        let mut out = [SensorData::default(); 4];
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 0);
+        assert_eq!(mpu.fifo_pending.len(), 7);
+
+        // 溢出后 FIFO 内容错位：1.5 帧 + 缓存 7 字节，共丢弃 3 帧
+        mpu.interface.push_fifo(&frame);
+        mpu.interface.push_fifo(&frame[..6]);
+        mpu.interface.regs[INT_STATUS as usize] = INT_STATUS_FIFO_OFLOW;
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 0);
+        assert_eq!(
+            mpu.fifo_status(),
+            FifoStatus {
+                overflow_count: 1,
+                dropped_frames: 3,
+            }
+        );
+        assert_eq!(mpu.fifo_pending.len(), 0);
+        assert_eq!(mpu.interface.regs[USER_CTRL as usize], USER_CTRL_FIFO_EN);
+        assert_eq!(mpu.interface.regs[FIFO_EN as usize], 0x78);
+
+        // 复位后从帧边界重新对齐
+        mpu.interface.regs[INT_STATUS as usize] = 0;
+        mpu.interface.push_fifo(&frame);
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 1);
+        assert!((out[0].accel.2 - 9.81).abs() < 1e-3);
+
+        mpu.clear_fifo_status();
+        assert_eq!(mpu.fifo_status(), FifoStatus::default());
+    }
+
+    #[test]
+    fn test_read_fifo_frames_after_channel_change() {
+        use crate::config::Mpu6050Config;
+        use crate::interface::mock::{MockInterface, block_on};
+
+        let mut iface = MockInterface::new();
+        iface.push_fifo(&[0; 10]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+        let mut out = [SensorData::default(); 1];
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 0);
+        assert_eq!(mpu.fifo_pending.len(), 10);
+
+        // 直接修改通道后，缓存的旧格式字节被丢弃，新数据按 2 字节帧解码
+        mpu.config.fifo_channels = FifoChannels::GYRO_X;
+        mpu.interface.push_fifo(&[0, 0x83, 0, 0x83]);
+        assert_eq!(block_on(mpu.read_fifo_frames(&mut out)).unwrap(), 1);
+        assert!((out[0].gyro.0 - crate::DEG2RAD).abs() < 1e-4);
+        assert_eq!(mpu.fifo_pending.len(), 0);
+    }
+
+    #[test]
+    fn test_plan_caps_read_and_detects_overflow() {
+        let mut pending = FifoPending::default();
+        let mut status = FifoStatus::default();
+        for byte in 0..4u8 {
+            pending.push(byte, 12);
+        }
+        // 输出可容纳 2 帧，已缓存 4 字节
+        assert_eq!(
+            pending.plan(&mut status, 0, 100, 1024, 12, 2),
+            FifoRead::Bytes(20)
+        );
+        assert_eq!(
+            pending.plan(&mut status, 0, 10, 1024, 12, 2),
+            FifoRead::Bytes(10)
+        );
+        assert_eq!(
+            pending.plan(&mut status, 0, 1024, 1024, 12, 2),
+            FifoRead::Overflow
+        );
+        assert_eq!(pending.len(), 0);
+        assert_eq!(status.dropped_frames, 86);
+    }
+
+    #[test]
+    fn test_plan_handles_layout_change_and_short_output() {
+        let mut pending = FifoPending::default();
+        let mut status = FifoStatus::default();
+        assert_eq!(
+            pending.plan(&mut status, 0, 0, 1024, 14, 1),
+            FifoRead::Bytes(0)
+        );
+        for byte in 0..10u8 {
+            pending.push(byte, 14);
+        }
+        // 通道改为仅陀螺仪（6 字节帧），缓存的 10 字节属于旧格式
+        assert_eq!(
+            pending.plan(&mut status, 0, 100, 1024, 6, 1),
+            FifoRead::Bytes(6)
+        );
+        assert_eq!(pending.len(), 0);
+
+        // 缓存多于输出所需字节时不再读取
+        for byte in 0..5u8 {
+            pending.push(byte, 6);
+        }
+        assert_eq!(
+            pending.plan(&mut status, 0, 100, 1024, 6, 0),
+            FifoRead::Bytes(0)
+        );
+        assert_eq!(pending.len(), 5);
+    }
+
+    #[test]
+    fn test_pending_keeps_partial_frame() {
+        let mut pending = FifoPending::default();
+        for byte in 0..5u8 {
+            assert!(pending.push(byte, 6).is_none());
+        }
+        assert_eq!(pending.len(), 5);
+        let frame = pending.push(5, 6).unwrap();
+        assert_eq!(&frame[..6], &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(pending.len(), 0);
     }
 }
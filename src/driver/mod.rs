@@ -1,71 +1,151 @@
 //! MPU6050 驱动相关API，详见 [`Mpu6050`] 结构体。
 
 pub use crate::Mpu6050;
+pub use chip::ChipVariant;
+pub(crate) use fifo::FifoPending;
+pub use fifo::FifoStatus;
+pub use self_test::{AxisSelfTest, SelfTestReport};
+pub use timing::MAX_AUTO_DT;
 
-mod calibrate;
+pub(crate) mod calibrate;
+mod chip;
 mod config_ops;
-mod fifo;
+pub(crate) mod convert;
+pub(crate) mod fifo;
 mod interrupt;
-mod power;
+pub(crate) mod offset;
+pub(crate) mod power;
 mod read;
 mod reg_rw;
+pub(crate) mod self_test;
+pub(crate) mod timing;
 
 use crate::config::Mpu6050Config;
-use crate::config::calculate_sample_rate_divider;
+use crate::error::{ConfigValidation, Mpu6050Error, Result};
+use crate::interface::{I2cAddress, I2cInterface, Interface, SpiDeviceInterface, SpiInterface};
 use crate::register::*;
 use embassy_time::Timer;
 use embedded_hal::digital::OutputPin;
-use embedded_hal_async::spi::SpiBus;
+use embedded_hal_async::i2c::I2c;
+use embedded_hal_async::spi::{SpiBus, SpiDevice};
 
-impl<SPI, CS, T> Mpu6050<SPI, CS, T>
+impl<SPI, CS, T> Mpu6050<SpiInterface<SPI, CS>, T>
 where
     SPI: SpiBus<u8>,
     CS: OutputPin,
     T: crate::numeric::NumericType,
 {
-    /// 创建新的MPU6050实例
+    /// 创建基于独占SPI总线和片选引脚的MPU6050实例
     pub fn new(spi: SPI, cs: CS, config: Mpu6050Config) -> Self {
+        Self::with_interface(SpiInterface::new(spi, cs), config)
+    }
+}
+
+impl<SPI, T> Mpu6050<SpiDeviceInterface<SPI>, T>
+where
+    SPI: SpiDevice<u8>,
+    T: crate::numeric::NumericType,
+{
+    /// 创建基于 `SpiDevice` 的MPU6050实例
+    ///
+    /// 适用于IMU与其他外设共享SPI总线的场景，片选由 `SpiDevice` 管理
+    pub fn new_spi_device(spi: SPI, config: Mpu6050Config) -> Self {
+        Self::with_interface(SpiDeviceInterface::new(spi), config)
+    }
+}
+
+impl<I2C, T> Mpu6050<I2cInterface<I2C>, T>
+where
+    I2C: I2c,
+    T: crate::numeric::NumericType,
+{
+    /// 创建基于I2C的MPU6050实例
+    ///
+    /// `address` 由 AD0 引脚电平决定（0x68/0x69）
+    pub fn new_i2c(i2c: I2C, address: I2cAddress, config: Mpu6050Config) -> Self {
+        Self::with_interface(I2cInterface::new(i2c, address), config)
+    }
+}
+
+impl<DI, T> Mpu6050<DI, T>
+where
+    DI: Interface,
+    T: crate::numeric::NumericType,
+{
+    /// 使用任意总线接口创建MPU6050实例
+    pub fn with_interface(interface: DI, config: Mpu6050Config) -> Self {
         Self {
-            spi,
-            cs,
+            interface,
             config,
+            chip: ChipVariant::default(),
             accel_offset: (0, 0, 0),
             gyro_offset: (0, 0, 0),
-            // last_update: None,
+            accel_calibration: crate::calibration::AccelCalibration::identity(),
+            gyro_temp_model: None,
+            last_update: None,
+            fifo_pending: FifoPending::default(),
+            fifo_status: FifoStatus::default(),
             pitch: T::zero(),
             roll: T::zero(),
             yaw: T::zero(),
         }
     }
 
+    /// 释放并返回底层总线接口
+    pub fn release(self) -> DI {
+        self.interface
+    }
+
+    /// 当前识别到的芯片型号（[`init`](Self::init) 之前为默认的 MPU6050）
+    pub fn chip_variant(&self) -> ChipVariant {
+        self.chip
+    }
+
     /// 基本初始化
-    pub async fn init(&mut self) -> Result<(), SPI::Error> {
+    ///
+    /// 读取 WHO_AM_I 识别芯片型号，ID不匹配时返回 [`Mpu6050Error::DeviceNotFound`]
+    pub async fn init(&mut self) -> Result<ChipVariant, DI::Error> {
+        let id = self.who_am_i().await?;
+        self.chip = ChipVariant::from_who_am_i(id).ok_or(Mpu6050Error::DeviceNotFound)?;
         // 唤醒设备
         self.write_register(PWR_MGMT_1, 0x00).await?;
         Timer::after_millis(100).await;
-        Ok(())
+        Ok(self.chip)
     }
 
     /// 完整初始化和配置
-    pub async fn init_with_config(&mut self) -> Result<(), SPI::Error> {
+    ///
+    /// 配置无效时返回 [`Mpu6050Error::InvalidConfig`]
+    pub async fn init_with_config(&mut self) -> Result<(), DI::Error> {
+        self.config
+            .validate()
+            .map_err(|_| Mpu6050Error::InvalidConfig)?;
         self.init().await?;
 
         self.set_clock_source(self.config.clock_source).await?;
-        let sample_rate_div = calculate_sample_rate_divider(self.config.sample_rate);
+        let sample_rate_div = self.config.sample_rate_divider();
         self.write_register(SMPLRT_DIV, sample_rate_div).await?;
         self.write_register(CONFIG, self.config.dlpf_config as u8)
             .await?;
+        if self.chip.is_mpu6500_family() {
+            // MPU6500系列的加速度计DLPF独立配置
+            self.write_register(ACCEL_CONFIG2, self.config.dlpf_config as u8)
+                .await?;
+        }
         self.set_gyro_scale(self.config.gyro_scale).await?;
         self.set_accel_scale(self.config.accel_scale).await?;
         if self.config.enable_interrupts {
             self.enable_interrupts().await?;
         }
+        if self.config.enable_fifo {
+            self.enable_fifo().await?;
+        }
 
         Ok(())
     }
 
     /// 校准初始化（包含传感器校准）
-    pub async fn calibrate_init(&mut self, cycle: u16) -> Result<(), SPI::Error> {
+    pub async fn calibrate_init(&mut self, cycle: u16) -> Result<(), DI::Error> {
         self.init_with_config().await?;
         self.calibrate_sensors(cycle).await?;
         Ok(())
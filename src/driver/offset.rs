@@ -0,0 +1,205 @@
+use super::convert::{average_offset, decode_i16x3};
+use crate::Mpu6050;
+use crate::calibration::AccelCalibration;
+use crate::config::Mpu6050Config;
+use crate::error::{Mpu6050Error, Result};
+use crate::interface::Interface;
+use crate::register::*;
+use embassy_time::Timer;
+
+/// 陀螺仪偏移寄存器（X/Y/Z高字节地址）
+pub(crate) const GYRO_OFFSET_REGISTERS: [u8; 3] = [XG_OFFS_USRH, YG_OFFS_USRH, ZG_OFFS_USRH];
+
+/// 偏移寄存器换算：新值 = 旧值 - 平均偏差 × (寄存器量程 / 当前量程)，结果限幅到 i16
+fn trim_register(current: i16, avg_raw: i32, register_lsb: f32, current_lsb: f32) -> i16 {
+    let delta = libm::roundf(avg_raw as f32 * register_lsb / current_lsb) as i32;
+    (current as i32 - delta).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+}
+
+/// 加速度计偏移寄存器写入值：第0位保留芯片原有的温度补偿位
+pub(crate) fn pack_accel_offset(new: i16, old: i16) -> i16 {
+    (new & !ACCEL_OFFSET_TEMP_COMP_BIT) | (old & ACCEL_OFFSET_TEMP_COMP_BIT)
+}
+
+/// 由芯片系静置采样累加值计算新的加速度计偏移寄存器值（机体 z 轴朝上）
+pub(crate) fn accel_hardware_trim(
+    sum: (i32, i32, i32),
+    cycle: u16,
+    current: (i16, i16, i16),
+    config: &Mpu6050Config,
+) -> (i16, i16, i16) {
+    let accel_lsb = config.accel_scale.get_scale_factor();
+    let (ax, ay, az) = average_offset(sum, cycle);
+    // 机体 z 轴朝上时重力在芯片系中的方向
+    let (gx, gy, gz) = config.mounting.to_chip((0, 0, accel_lsb as i16));
+    // 减去重力加速度，读数饱和时差值可能超出 i16
+    let (ax, ay, az) = (
+        ax as i32 - gx as i32,
+        ay as i32 - gy as i32,
+        az as i32 - gz as i32,
+    );
+    (
+        trim_register(current.0, ax, ACCEL_SCALE_16G, accel_lsb),
+        trim_register(current.1, ay, ACCEL_SCALE_16G, accel_lsb),
+        trim_register(current.2, az, ACCEL_SCALE_16G, accel_lsb),
+    )
+}
+
+/// 由静置采样累加值计算新的陀螺仪偏移寄存器值
+pub(crate) fn gyro_hardware_trim(
+    sum: (i32, i32, i32),
+    cycle: u16,
+    current: (i16, i16, i16),
+    config: &Mpu6050Config,
+) -> (i16, i16, i16) {
+    let gyro_lsb = config.gyro_scale.get_scale_factor();
+    let (gx, gy, gz) = average_offset(sum, cycle);
+    (
+        trim_register(current.0, gx as i32, GYRO_SCALE_1000, gyro_lsb),
+        trim_register(current.1, gy as i32, GYRO_SCALE_1000, gyro_lsb),
+        trim_register(current.2, gz as i32, GYRO_SCALE_1000, gyro_lsb),
+    )
+}
+
+impl<DI, T> Mpu6050<DI, T>
+where
+    DI: Interface,
+    T: crate::numeric::NumericType,
+{
+    /// 读取加速度计片上偏移寄存器（±16g 量程单位，第0位为温度补偿位）
+    pub async fn read_accel_offset_registers(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let regs = self.chip.accel_offset_registers();
+        let mut out = [0i16; 3];
+        for (reg, val) in regs.into_iter().zip(out.iter_mut()) {
+            let mut buf = [0u8; 2];
+            self.read_registers(reg, &mut buf).await?;
+            *val = i16::from_be_bytes(buf);
+        }
+        Ok((out[0], out[1], out[2]))
+    }
+
+    /// 写入加速度计片上偏移寄存器
+    ///
+    /// 第0位为出厂温度补偿位，写入时保留芯片中的原值
+    pub async fn write_accel_offset_registers(
+        &mut self,
+        offset: (i16, i16, i16),
+    ) -> Result<(), DI::Error> {
+        let current = self.read_accel_offset_registers().await?;
+        let regs = self.chip.accel_offset_registers();
+        let pairs = [
+            (offset.0, current.0),
+            (offset.1, current.1),
+            (offset.2, current.2),
+        ];
+        for (reg, (new, old)) in regs.into_iter().zip(pairs) {
+            let [high, low] = pack_accel_offset(new, old).to_be_bytes();
+            self.write_register(reg, high).await?;
+            self.write_register(reg + 1, low).await?;
+        }
+        Ok(())
+    }
+
+    /// 读取陀螺仪片上偏移寄存器（XG_OFFS_USRH..ZG_OFFS_USRL，±1000°/s 量程单位）
+    pub async fn read_gyro_offset_registers(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let mut buf = [0u8; 6];
+        self.read_registers(XG_OFFS_USRH, &mut buf).await?;
+        Ok(decode_i16x3(&buf))
+    }
+
+    /// 写入陀螺仪片上偏移寄存器
+    pub async fn write_gyro_offset_registers(
+        &mut self,
+        offset: (i16, i16, i16),
+    ) -> Result<(), DI::Error> {
+        for (reg, val) in GYRO_OFFSET_REGISTERS
+            .into_iter()
+            .zip([offset.0, offset.1, offset.2])
+        {
+            let [high, low] = val.to_be_bytes();
+            self.write_register(reg, high).await?;
+            self.write_register(reg + 1, low).await?;
+        }
+        Ok(())
+    }
+
+    /// 计算并写入片上偏移寄存器
+    ///
+    /// 设备需水平静置（机体Z轴朝上）。采样与偏移寄存器均使用芯片坐标系，
+    /// 重力方向按安装方向换算；偏差直接在芯片内补偿，
+    /// FIFO 与 DMP 输出同样受益。
+    /// 软件偏移、加速度计标定与陀螺仪温度模型都基于未修正的输出得出，完成后一并重置，
+    /// 避免同一偏差被扣除两次。
+    ///
+    /// `cycle` 为0时返回 [`Mpu6050Error::CalibrationFailed`]
+    pub async fn calibrate_hardware_offsets(&mut self, cycle: u16) -> Result<(), DI::Error> {
+        if cycle == 0 {
+            return Err(Mpu6050Error::CalibrationFailed);
+        }
+        let mut accel_sum = (0i32, 0i32, 0i32);
+        let mut gyro_sum = (0i32, 0i32, 0i32);
+        for _ in 0..cycle {
+            let raw = self.read_all_raw_chip().await?;
+            accel_sum.0 += raw.accel.0 as i32;
+            accel_sum.1 += raw.accel.1 as i32;
+            accel_sum.2 += raw.accel.2 as i32;
+            gyro_sum.0 += raw.gyro.0 as i32;
+            gyro_sum.1 += raw.gyro.1 as i32;
+            gyro_sum.2 += raw.gyro.2 as i32;
+            Timer::after_micros(500).await;
+        }
+
+        let current = self.read_accel_offset_registers().await?;
+        let accel_trim = accel_hardware_trim(accel_sum, cycle, current, &self.config);
+        self.write_accel_offset_registers(accel_trim).await?;
+
+        let current = self.read_gyro_offset_registers().await?;
+        let gyro_trim = gyro_hardware_trim(gyro_sum, cycle, current, &self.config);
+        self.write_gyro_offset_registers(gyro_trim).await?;
+
+        self.accel_offset = (0, 0, 0);
+        self.gyro_offset = (0, 0, 0);
+        self.accel_calibration = AccelCalibration::identity();
+        self.gyro_temp_model = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{accel_hardware_trim, trim_register};
+    use crate::Mpu6050;
+    use crate::config::Mpu6050Config;
+    use crate::interface::mock::{MockInterface, block_on};
+    use crate::register::*;
+
+    #[test]
+    fn test_trim_register_scales_to_register_units() {
+        // ±2g 下偏差 800 LSB，对应 ±16g 寄存器单位 100
+        assert_eq!(trim_register(0, 800, ACCEL_SCALE_16G, ACCEL_SCALE_2G), -100);
+        // ±250°/s 下偏差 -131 LSB，对应 ±1000°/s 寄存器单位约 33
+        assert_eq!(trim_register(10, -131, GYRO_SCALE_1000, GYRO_SCALE_250), 43);
+    }
+
+    #[test]
+    fn test_accel_trim_keeps_saturated_difference_in_i32() {
+        // ±2g 下 z 轴饱和在 -32768，减去 1g 后为 -49152，超出 i16
+        let trim = accel_hardware_trim((0, 0, -32768 * 4), 4, (0, 0, 0), &Mpu6050Config::default());
+        assert_eq!(trim, (0, 0, 6144));
+        assert_eq!(
+            trim_register(i16::MAX - 10, -40000, ACCEL_SCALE_16G, ACCEL_SCALE_2G),
+            i16::MAX
+        );
+    }
+
+    #[test]
+    fn test_write_accel_offset_preserves_temp_comp_bit() {
+        let mut iface = MockInterface::new();
+        iface.regs[XA_OFFS_USRL as usize] = 0x01;
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+
+        block_on(mpu.write_accel_offset_registers((0x0100, 0x0101, -2))).unwrap();
+        let offsets = block_on(mpu.read_accel_offset_registers()).unwrap();
+        assert_eq!(offsets, (0x0101, 0x0100, -2));
+    }
+}
@@ -1,18 +1,22 @@
 use crate::Mpu6050;
 use crate::config::*;
+use crate::error::{Mpu6050Error, Result};
+use crate::interface::Interface;
 use crate::register::*;
 use embassy_time::Timer;
-use embedded_hal::digital::OutputPin;
-use embedded_hal_async::spi::SpiBus;
 
-impl<SPI, CS, T> Mpu6050<SPI, CS, T>
+/// 复位完成轮询间隔（毫秒）
+pub(crate) const RESET_POLL_MS: u32 = 10;
+/// 复位完成最大轮询次数
+pub(crate) const RESET_POLL_ATTEMPTS: u32 = 10;
+
+impl<DI, T> Mpu6050<DI, T>
 where
-    SPI: SpiBus<u8>,
-    CS: OutputPin,
+    DI: Interface,
     T: crate::numeric::NumericType,
 {
     /// 进入睡眠模式
-    pub async fn sleep(&mut self) -> Result<(), SPI::Error> {
+    pub async fn sleep(&mut self) -> Result<(), DI::Error> {
         let current = self.read_register(PWR_MGMT_1).await?;
         self.write_register(PWR_MGMT_1, current | PowerMode::Sleep as u8)
             .await?;
@@ -20,7 +24,7 @@ where
     }
 
     /// 唤醒设备
-    pub async fn wake(&mut self) -> Result<(), SPI::Error> {
+    pub async fn wake(&mut self) -> Result<(), DI::Error> {
         let current = self.read_register(PWR_MGMT_1).await?;
         self.write_register(PWR_MGMT_1, current & !(PowerMode::Sleep as u8))
             .await?;
@@ -29,9 +33,16 @@ where
     }
 
     /// 重置设备
-    pub async fn reset(&mut self) -> Result<(), SPI::Error> {
-        self.write_register(PWR_MGMT_1, 0x80).await?;
-        Timer::after_millis(100).await;
-        Ok(())
+    ///
+    /// 轮询 DEVICE_RESET 位直到复位完成，超时返回 [`Mpu6050Error::Timeout`]
+    pub async fn reset(&mut self) -> Result<(), DI::Error> {
+        self.write_register(PWR_MGMT_1, DEVICE_RESET).await?;
+        for _ in 0..RESET_POLL_ATTEMPTS {
+            Timer::after_millis(RESET_POLL_MS as u64).await;
+            if self.read_register(PWR_MGMT_1).await? & DEVICE_RESET == 0 {
+                return Ok(());
+            }
+        }
+        Err(Mpu6050Error::Timeout)
     }
 }
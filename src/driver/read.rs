@@ -1,110 +1,115 @@
+use super::convert::{
+    GRAVITY, SENSOR_BURST_LEN, TEMP_GYRO_BURST_LEN, accel_from_raw, compensate_gyro, decode_i16x3,
+    decode_sensor_burst, gyro_from_raw, mount_raw, sensor_data_from_raw, temp_from_raw,
+};
+use crate::error::Result;
+use crate::interface::Interface;
 use crate::register::*;
-use crate::{Mpu6050, SensorData, numeric::NumericType};
+use crate::{Mpu6050, RawSensorData, SensorData, fusion, numeric::NumericType};
 
-use embedded_hal::digital::OutputPin;
-use embedded_hal_async::spi::SpiBus;
-
-impl<SPI, CS, T> Mpu6050<SPI, CS, T>
+impl<DI, T> Mpu6050<DI, T>
 where
-    SPI: SpiBus<u8>,
-    CS: OutputPin,
+    DI: Interface,
     T: NumericType,
 {
     // ================== 初始化与校准 ==================
     /// 读取设备ID（WHO_AM_I寄存器），用于检测设备是否连接正常。
-    pub async fn who_am_i(&mut self) -> Result<u8, SPI::Error> {
-        let mut buf = [WHO_AM_I | 0x80, 0];
-        self.cs.set_low().ok();
-        self.spi.transfer_in_place(&mut buf).await?;
-        self.cs.set_high().ok();
-        Ok(buf[1])
+    pub async fn who_am_i(&mut self) -> Result<u8, DI::Error> {
+        self.read_register(WHO_AM_I).await
     }
 
     // ================== 数据采集 ==================
-    /// 读取原始加速度计数据（三轴，单位：原始ADC）
-    pub async fn read_accel_raw(&mut self) -> Result<(i16, i16, i16), SPI::Error> {
-        let mut buf = [ACCEL_XOUT_H | 0x80, 0, 0, 0, 0, 0, 0];
-        self.cs.set_low().ok();
-        self.spi.transfer_in_place(&mut buf).await?;
-        self.cs.set_high().ok();
-        let x = ((buf[1] as i16) << 8) | (buf[2] as i16);
-        let y = ((buf[3] as i16) << 8) | (buf[4] as i16);
-        let z = ((buf[5] as i16) << 8) | (buf[6] as i16);
-        Ok((x, y, z))
+    /// 读取原始加速度计数据（三轴，单位：原始ADC，已按安装方向转换到机体系）
+    pub async fn read_accel_raw(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let mut buf = [0u8; 6];
+        self.read_registers(ACCEL_XOUT_H, &mut buf).await?;
+        Ok(self.config.mounting.to_body(decode_i16x3(&buf)))
     }
 
     /// 读取校准后的加速度计数据（三轴，单位：m/s²）
-    pub async fn read_accel(&mut self) -> Result<(T, T, T), SPI::Error> {
-        let (x, y, z) = self.read_accel_raw().await?;
-        let x = x - self.accel_offset.0;
-        let y = y - self.accel_offset.1;
-        let z = z - self.accel_offset.2;
-        let scale = self.config.accel_scale.get_scale_factor();
-        let x = T::from_f32((x as f32) * 9.81 / scale);
-        let y = T::from_f32((y as f32) * 9.81 / scale);
-        let z = T::from_f32((z as f32) * 9.81 / scale);
-        Ok((x, y, z))
-    }
-
-    /// 读取原始陀螺仪数据（三轴，单位：原始ADC）
-    pub async fn read_gyro_raw(&mut self) -> Result<(i16, i16, i16), SPI::Error> {
-        self.cs.set_low().ok();
-        let mut buf = [GYRO_XOUT_H | 0x80, 0, 0, 0, 0, 0, 0];
-        self.spi.transfer_in_place(&mut buf).await?;
-        self.cs.set_high().ok();
-        let x = ((buf[1] as i16) << 8) | (buf[2] as i16);
-        let y = ((buf[3] as i16) << 8) | (buf[4] as i16);
-        let z = ((buf[5] as i16) << 8) | (buf[6] as i16);
-        Ok((x, y, z))
+    ///
+    /// 依次扣除软件偏移并应用 [`AccelCalibration`](crate::calibration::AccelCalibration)
+    pub async fn read_accel(&mut self) -> Result<(T, T, T), DI::Error> {
+        let raw = self.read_accel_raw().await?;
+        let accel = accel_from_raw(raw, self.accel_offset, self.config.accel_scale);
+        Ok(self.accel_calibration.apply(accel))
+    }
+
+    /// 读取原始陀螺仪数据（三轴，单位：原始ADC，已按安装方向转换到机体系）
+    pub async fn read_gyro_raw(&mut self) -> Result<(i16, i16, i16), DI::Error> {
+        let mut buf = [0u8; 6];
+        self.read_registers(GYRO_XOUT_H, &mut buf).await?;
+        Ok(self.config.mounting.to_body(decode_i16x3(&buf)))
     }
 
     /// 读取校准后的陀螺仪数据（三轴，单位：rad/s）
-    pub async fn read_gyro(&mut self) -> Result<(T, T, T), SPI::Error> {
-        let (x, y, z) = self.read_gyro_raw().await?;
-        let x = x - self.gyro_offset.0;
-        let y = y - self.gyro_offset.1;
-        let z = z - self.gyro_offset.2;
-        let scale = self.config.gyro_scale.get_scale_factor();
-        let x = T::from_f32((x as f32) * core::f32::consts::PI / (180.0 * scale));
-        let y = T::from_f32((y as f32) * core::f32::consts::PI / (180.0 * scale));
-        let z = T::from_f32((z as f32) * core::f32::consts::PI / (180.0 * scale));
-        Ok((x, y, z))
+    ///
+    /// 设置了温度模型时，温度与角速度在一次突发读取中获取并按当前温度扣除零偏
+    pub async fn read_gyro(&mut self) -> Result<(T, T, T), DI::Error> {
+        let Some(model) = self.gyro_temp_model else {
+            let raw = self.read_gyro_raw().await?;
+            return Ok(gyro_from_raw(raw, self.gyro_offset, self.config.gyro_scale));
+        };
+        let mut buf = [0u8; TEMP_GYRO_BURST_LEN];
+        self.read_registers(TEMP_OUT_H, &mut buf).await?;
+        let temp: T = temp_from_raw(i16::from_be_bytes([buf[0], buf[1]]), self.chip);
+        let raw = self.config.mounting.to_body(decode_i16x3(&buf[2..]));
+        let gyro = gyro_from_raw(raw, self.gyro_offset, self.config.gyro_scale);
+        Ok(compensate_gyro(gyro, &model, temp))
     }
 
     /// 读取温度（单位：摄氏度）
-    pub async fn read_temp(&mut self) -> Result<T, SPI::Error> {
-        let mut buf = [TEMP_OUT_H | 0x80, 0, 0];
-        self.cs.set_low().ok();
-        self.spi.transfer_in_place(&mut buf).await?;
-        self.cs.set_high().ok();
-        let raw = ((buf[1] as i16) << 8) | (buf[2] as i16);
-        Ok(T::from_f32((raw as f32) / TEMP_SCALE + TEMP_OFFSET))
+    pub async fn read_temp(&mut self) -> Result<T, DI::Error> {
+        let mut buf = [0u8; 2];
+        self.read_registers(TEMP_OUT_H, &mut buf).await?;
+        Ok(temp_from_raw(i16::from_be_bytes(buf), self.chip))
     }
 
-    /// 读取所有传感器数据（加速度、陀螺仪、温度，单位：物理量）
-    pub async fn read_all(&mut self) -> Result<SensorData<T>, SPI::Error> {
-        let accel = self.read_accel().await?;
-        let gyro = self.read_gyro().await?;
-        let temp = self.read_temp().await?;
+    /// 一次突发读取全部原始数据（ACCEL_XOUT_H..GYRO_ZOUT_L，14字节）
+    ///
+    /// 加速度、温度、陀螺仪保证属于同一采样周期，三轴数据已按安装方向转换到机体系
+    pub async fn read_all_raw(&mut self) -> Result<RawSensorData, DI::Error> {
+        let raw = self.read_all_raw_chip().await?;
+        Ok(mount_raw(raw, &self.config.mounting))
+    }
+
+    /// 芯片坐标系下的突发读取，供片上偏移校准和自检使用
+    pub(crate) async fn read_all_raw_chip(&mut self) -> Result<RawSensorData, DI::Error> {
+        let mut buf = [0u8; SENSOR_BURST_LEN];
+        self.read_registers(ACCEL_XOUT_H, &mut buf).await?;
+        Ok(decode_sensor_burst(&buf))
+    }
 
-        Ok(SensorData { accel, gyro, temp })
+    /// 读取所有传感器数据（加速度、陀螺仪、温度，单位：物理量）
+    ///
+    /// 基于 [`read_all_raw`](Self::read_all_raw) 的单次突发读取
+    pub async fn read_all(&mut self) -> Result<SensorData<T>, DI::Error> {
+        let raw = self.read_all_raw().await?;
+        Ok(sensor_data_from_raw(
+            &raw,
+            self.accel_offset,
+            self.gyro_offset,
+            &self.accel_calibration,
+            self.gyro_temp_model.as_ref(),
+            &self.config,
+            self.chip,
+        ))
     }
 
     // ================== 姿态解算与滤波 ==================
     /// 仅用加速度计计算 pitch/roll（静态欧拉角，弧度）
-    pub async fn calculate_pitch_roll_from_accel(&mut self) -> Result<(T, T), SPI::Error> {
-        let (ax, ay, az) = self.read_accel().await?;
-        let pitch_a = T::atan2(ax, (ay * ay + az * az).sqrt());
-        let roll_a = T::atan2(ay, az);
-        Ok((pitch_a, roll_a))
+    pub async fn calculate_pitch_roll_from_accel(&mut self) -> Result<(T, T), DI::Error> {
+        let accel = self.read_accel().await?;
+        Ok(fusion::pitch_roll_from_accel(accel))
     }
 
     /// 仅用陀螺仪积分更新 pitch/roll/yaw（弧度）
-    pub async fn integrate_gyro(&mut self, dt: T) -> Result<(T, T, T), SPI::Error> {
-        let (gx, gy, gz) = self.read_gyro().await?;
-        self.pitch += gx * dt;
-        self.roll += gy * dt;
-        self.yaw += gz * dt;
+    ///
+    /// 机体角速度经欧拉角运动学转换后积分，yaw 包裹到 ±π，坐标系约定见 [`crate::fusion`]
+    pub async fn integrate_gyro(&mut self, dt: T) -> Result<(T, T, T), DI::Error> {
+        let gyro = self.read_gyro().await?;
+        (self.pitch, self.roll, self.yaw) =
+            fusion::integrate_gyro((self.pitch, self.roll, self.yaw), gyro, dt);
         Ok((self.pitch, self.roll, self.yaw))
     }
 
@@ -118,10 +123,8 @@ where
         yaw_g: T,
         alpha: T,
     ) {
-        let one = T::one();
-        self.pitch = alpha * pitch_g + (one - alpha) * acc_pitch;
-        self.roll = alpha * roll_g + (one - alpha) * acc_roll;
-        self.yaw = yaw_g;
+        (self.pitch, self.roll, self.yaw) =
+            fusion::complementary_filter(acc_pitch, acc_roll, (pitch_g, roll_g, yaw_g), alpha);
     }
 
     /// 获取当前欧拉角（单位：度）
@@ -133,6 +136,49 @@ where
         )
     }
 
+    /// 当前姿态（pitch/roll/yaw）对应的四元数（机体系 → 世界系）
+    pub fn attitude_quaternion(&self) -> fusion::Quaternion<T> {
+        fusion::Quaternion::from_euler(self.pitch, self.roll, self.yaw)
+    }
+
+    /// 读取加速度并按当前姿态去除重力，返回机体系与世界系的线性加速度（m/s²）
+    ///
+    /// 姿态取自 [`update`](Self::update) 维护的 pitch/roll/yaw，调用前应先更新姿态
+    pub async fn read_linear_accel(&mut self) -> Result<fusion::LinearAcceleration<T>, DI::Error> {
+        let accel = self.read_accel().await?;
+        Ok(fusion::linear_acceleration(
+            &self.attitude_quaternion(),
+            accel,
+            T::from_f32(GRAVITY),
+        ))
+    }
+
+    /// 航位推算单步更新：单次突发读取后互补滤波更新姿态，去除重力并送入 `dead_reckoning`
+    ///
+    /// # 参数
+    /// - `dt`: 采样间隔（秒）
+    /// - `alpha`: 互补滤波系数，同 [`update`](Self::update)
+    pub async fn update_dead_reckoning(
+        &mut self,
+        dead_reckoning: &mut fusion::DeadReckoning<T>,
+        dt: T,
+        alpha: T,
+    ) -> Result<(), DI::Error> {
+        let data = self.read_all().await?;
+        let gyro_angles = fusion::integrate_gyro((self.pitch, self.roll, self.yaw), data.gyro, dt);
+        let (acc_pitch, acc_roll) = fusion::pitch_roll_from_accel(data.accel);
+        (self.pitch, self.roll, self.yaw) =
+            fusion::complementary_filter(acc_pitch, acc_roll, gyro_angles, alpha);
+
+        let linear = fusion::linear_acceleration(
+            &self.attitude_quaternion(),
+            data.accel,
+            T::from_f32(GRAVITY),
+        );
+        dead_reckoning.update(&linear, data.gyro, dt);
+        Ok(())
+    }
+
     /// 更新姿态角（pitch/roll/yaw），融合加速度计和陀螺仪数据
     ///
     /// # 参数
@@ -140,14 +186,14 @@ where
     /// - `alpha`: 互补滤波系数，范围 0~1，越大越依赖陀螺仪，越小越依赖加速度计
     ///
     /// # 返回
-    /// - `Result<(), SPI::Error>`: 操作结果，可能包含SPI通信错误
+    /// - `Result<(), DI::Error>`: 操作结果，可能包含总线通信错误
     ///
     /// # 算法说明
     /// 1. 读取加速度计和陀螺仪原始数据
     /// 2. 用加速度计计算 pitch/roll 的静态角度
     /// 3. 用互补滤波融合陀螺仪积分和加速度计角度，更新 pitch/roll
     /// 4. yaw 仅用陀螺仪积分
-    pub async fn update(&mut self, dt: T, alpha: T) -> Result<(), SPI::Error> {
+    pub async fn update(&mut self, dt: T, alpha: T) -> Result<(), DI::Error> {
         // 陀螺仪计算欧拉角
         let (pitch_g, roll_g, yaw_g) = self.integrate_gyro(dt).await?;
         // 加速度计计算欧拉角
@@ -158,3 +204,87 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Mpu6050;
+    use crate::config::Mpu6050Config;
+    use crate::interface::mock::{MockInterface, block_on};
+    use crate::register::*;
+
+    #[test]
+    fn test_read_accel_raw_decodes_big_endian() {
+        let mut iface = MockInterface::new();
+        iface.regs[ACCEL_XOUT_H as usize..ACCEL_XOUT_H as usize + 6]
+            .copy_from_slice(&[0x40, 0x00, 0xFF, 0xFE, 0x80, 0x00]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+
+        let raw = block_on(mpu.read_accel_raw()).unwrap();
+        assert_eq!(raw, (16384, -2, i16::MIN));
+    }
+
+    #[test]
+    fn test_read_all_raw_single_burst() {
+        let mut iface = MockInterface::new();
+        iface.regs[ACCEL_XOUT_H as usize..=GYRO_ZOUT_L as usize].copy_from_slice(&[
+            0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x12, 0x34, 0xFF, 0xFF, 0x00, 0x05, 0x00, 0x06,
+        ]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+
+        let raw = block_on(mpu.read_all_raw()).unwrap();
+        assert_eq!(mpu.interface.read_count, 1);
+        assert_eq!(mpu.interface.last_read, Some((ACCEL_XOUT_H, 14)));
+        assert_eq!(raw.accel, (1, 2, 3));
+        assert_eq!(raw.temp, 0x1234);
+        assert_eq!(raw.gyro, (-1, 5, 6));
+    }
+
+    #[test]
+    fn test_read_gyro_applies_temp_model() {
+        use crate::calibration::GyroTempModel;
+
+        let mut iface = MockInterface::new();
+        // 温度原始值 340 → 37.53°C；x 轴 131 LSB = 1°/s
+        iface.regs[TEMP_OUT_H as usize..TEMP_OUT_H as usize + 2].copy_from_slice(&[0x01, 0x54]);
+        iface.regs[GYRO_XOUT_H as usize..GYRO_XOUT_H as usize + 2].copy_from_slice(&[0x00, 0x83]);
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, Mpu6050Config::default());
+        mpu.gyro_offset = (5, 5, 5);
+
+        let one_dps = crate::DEG2RAD;
+        mpu.set_gyro_temp_model(Some(GyroTempModel {
+            reference_temp: 36.53,
+            coeffs: [[0.5 * one_dps, 0.5 * one_dps, 0.0], [0.0; 3], [0.0; 3]],
+        }));
+        assert_eq!(mpu.gyro_offset, (0, 0, 0));
+
+        let (gx, gy, _) = block_on(mpu.read_gyro()).unwrap();
+        assert!(gx.abs() < 1e-4 && gy.abs() < 1e-6);
+        let data = block_on(mpu.read_all()).unwrap();
+        assert!(data.gyro.0.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_mounting_applied_to_raw_reads() {
+        use crate::config::{MountAxis, Mounting};
+
+        let mut iface = MockInterface::new();
+        iface.regs[ACCEL_XOUT_H as usize..=GYRO_ZOUT_L as usize].copy_from_slice(&[
+            0x00, 0x01, 0x00, 0x02, 0xC0, 0x00, 0x12, 0x34, 0x00, 0x04, 0x00, 0x05, 0x00, 0x06,
+        ]);
+        let config = Mpu6050Config {
+            mounting: Mounting::axes(MountAxis::PosX, MountAxis::NegY).unwrap(),
+            ..Mpu6050Config::default()
+        };
+        let mut mpu: Mpu6050<_, f32> = Mpu6050::with_interface(iface, config);
+
+        // 翻面安装：芯片 z 朝下时机体 z 仍读到 +1g
+        assert_eq!(block_on(mpu.read_accel_raw()).unwrap(), (1, -2, 16384));
+        let raw = block_on(mpu.read_all_raw()).unwrap();
+        assert_eq!(raw.accel, (1, -2, 16384));
+        assert_eq!(raw.gyro, (4, -5, -6));
+        assert_eq!(
+            block_on(mpu.read_all_raw_chip()).unwrap().accel,
+            (1, 2, -16384)
+        );
+    }
+}
@@ -1,29 +1,24 @@
 use crate::Mpu6050;
+use crate::error::Result;
+use crate::interface::Interface;
 
-use embedded_hal::digital::OutputPin;
-use embedded_hal_async::spi::SpiBus;
-
-impl<SPI, CS, T> Mpu6050<SPI, CS, T>
+impl<DI, T> Mpu6050<DI, T>
 where
-    SPI: SpiBus<u8>,
-    CS: OutputPin,
+    DI: Interface,
     T: crate::numeric::NumericType,
 {
     /// 读取寄存器
-    pub async fn read_register(&mut self, reg: u8) -> Result<u8, SPI::Error> {
-        let mut buf = [reg | 0x80, 0];
-        self.cs.set_low().ok();
-        self.spi.transfer_in_place(&mut buf).await?;
-        self.cs.set_high().ok();
-        Ok(buf[1])
+    pub async fn read_register(&mut self, reg: u8) -> Result<u8, DI::Error> {
+        self.interface.read_register(reg).await
+    }
+
+    /// 连续读取多个寄存器
+    pub async fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), DI::Error> {
+        self.interface.read_registers(reg, buf).await
     }
 
     /// 写入寄存器
-    pub async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), SPI::Error> {
-        let buf = [reg & 0x7F, val];
-        self.cs.set_low().ok();
-        self.spi.write(&buf).await?;
-        self.cs.set_high().ok();
-        Ok(())
+    pub async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), DI::Error> {
+        self.interface.write_register(reg, val).await
     }
 }
@@ -0,0 +1,257 @@
+//! 硬件自检
+//!
+//! 开启自检激励后测量输出变化，与芯片出厂自检值比较，判断传感器是否损坏
+
+use super::ChipVariant;
+use crate::config::{AccelScale, GyroScale};
+use crate::error::Result;
+use crate::interface::Interface;
+use crate::register::*;
+use crate::{Mpu6050, RawSensorData};
+use embassy_time::Timer;
+
+/// 自检采样次数
+pub(crate) const SELF_TEST_SAMPLES: u16 = 50;
+/// 切换自检状态后的稳定时间（毫秒）
+pub(crate) const SELF_TEST_SETTLE_MS: u32 = 20;
+/// MPU6050 允许的最大偏差（%）
+const MPU6050_MAX_CHANGE: f32 = 14.0;
+
+/// 单轴自检结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisSelfTest {
+    /// 出厂自检响应（LSB）
+    pub factory_trim: f32,
+    /// 实测自检响应（开启自检输出 - 关闭自检输出，LSB）
+    pub response: f32,
+    /// 实测响应相对出厂值的偏差（%）
+    pub change_percent: f32,
+    /// 是否通过
+    pub passed: bool,
+}
+
+/// 自检报告
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelfTestReport {
+    /// 加速度计 X/Y/Z 结果
+    pub accel: [AxisSelfTest; 3],
+    /// 陀螺仪 X/Y/Z 结果
+    pub gyro: [AxisSelfTest; 3],
+}
+
+impl SelfTestReport {
+    /// 所有轴均通过
+    pub fn passed(&self) -> bool {
+        self.accel.iter().chain(self.gyro.iter()).all(|a| a.passed)
+    }
+}
+
+/// MPU6050 出厂自检值（加速度计 ±8g，陀螺仪 ±250°/s）
+fn mpu6050_factory_trim(regs: [u8; 4]) -> ([f32; 3], [f32; 3]) {
+    let accel_code = [
+        ((regs[0] >> 3) & 0x1C) | ((regs[3] >> 4) & 0x03),
+        ((regs[1] >> 3) & 0x1C) | ((regs[3] >> 2) & 0x03),
+        ((regs[2] >> 3) & 0x1C) | (regs[3] & 0x03),
+    ];
+    let accel = accel_code.map(|code| {
+        if code == 0 {
+            0.0
+        } else {
+            4096.0 * 0.34 * libm::powf(0.92 / 0.34, (code as f32 - 1.0) / 30.0)
+        }
+    });
+    let gyro_code = [regs[0] & 0x1F, regs[1] & 0x1F, regs[2] & 0x1F];
+    let mut gyro = gyro_code.map(|code| {
+        if code == 0 {
+            0.0
+        } else {
+            25.0 * 131.0 * libm::powf(1.046, code as f32 - 1.0)
+        }
+    });
+    // 数据手册：Y轴出厂值取负
+    gyro[1] = -gyro[1];
+    (accel, gyro)
+}
+
+/// MPU6500 系列出厂自检值（量程 ±2g / ±250°/s）
+fn mpu6500_factory_trim(code: u8) -> f32 {
+    if code == 0 {
+        0.0
+    } else {
+        2620.0 * libm::powf(1.01, code as f32 - 1.0)
+    }
+}
+
+/// 计算单轴结果，`ratio_ok` 根据 实测/出厂 比值判断是否通过
+fn evaluate(factory_trim: f32, response: f32, ratio_ok: impl Fn(f32) -> bool) -> AxisSelfTest {
+    if factory_trim == 0.0 {
+        // 出厂值缺失，无法评估
+        return AxisSelfTest {
+            factory_trim,
+            response,
+            change_percent: 0.0,
+            passed: false,
+        };
+    }
+    let ratio = response / factory_trim;
+    AxisSelfTest {
+        factory_trim,
+        response,
+        change_percent: (ratio - 1.0) * 100.0,
+        passed: ratio_ok(ratio),
+    }
+}
+
+/// 自检使用的量程：MPU6050 出厂值按 ±8g 标定，MPU6500 系列按 ±2g；陀螺仪均为 ±250°/s
+pub(crate) fn self_test_scales(chip: ChipVariant) -> (AccelScale, GyroScale) {
+    let accel_scale = match chip {
+        ChipVariant::Mpu6050 => AccelScale::Scale8G,
+        _ => AccelScale::Scale2G,
+    };
+    (accel_scale, GyroScale::Scale250)
+}
+
+/// 出厂自检寄存器：MPU6050 为 SELF_TEST_X..SELF_TEST_A 共4字节，
+/// MPU6500 系列为加速度计与陀螺仪各3字节
+pub(crate) fn factory_trim_registers(chip: ChipVariant) -> &'static [(u8, usize)] {
+    match chip {
+        ChipVariant::Mpu6050 => &[(SELF_TEST_X_ACCEL, 4)],
+        _ => &[(SELF_TEST_X_ACCEL, 3), (SELF_TEST_X_GYRO, 3)],
+    }
+}
+
+/// 由自检前后平均读数 `[ax, ay, az, gx, gy, gz]` 与出厂自检寄存器
+/// （按 [`factory_trim_registers`] 顺序拼接）生成报告
+pub(crate) fn self_test_report(
+    chip: ChipVariant,
+    normal: [f32; 6],
+    excited: [f32; 6],
+    factory: [u8; 6],
+) -> SelfTestReport {
+    let accel_response = [
+        excited[0] - normal[0],
+        excited[1] - normal[1],
+        excited[2] - normal[2],
+    ];
+    let gyro_response = [
+        excited[3] - normal[3],
+        excited[4] - normal[4],
+        excited[5] - normal[5],
+    ];
+
+    match chip {
+        ChipVariant::Mpu6050 => {
+            let (accel_ft, gyro_ft) =
+                mpu6050_factory_trim([factory[0], factory[1], factory[2], factory[3]]);
+            let ok = |ratio: f32| libm::fabsf(ratio - 1.0) * 100.0 <= MPU6050_MAX_CHANGE;
+            SelfTestReport {
+                accel: [0, 1, 2].map(|i| evaluate(accel_ft[i], accel_response[i], ok)),
+                gyro: [0, 1, 2].map(|i| evaluate(gyro_ft[i], gyro_response[i], ok)),
+            }
+        }
+        _ => SelfTestReport {
+            accel: [0, 1, 2].map(|i| {
+                evaluate(mpu6500_factory_trim(factory[i]), accel_response[i], |r| {
+                    (0.5..1.5).contains(&r)
+                })
+            }),
+            gyro: [0, 1, 2].map(|i| {
+                evaluate(
+                    mpu6500_factory_trim(factory[3 + i]),
+                    gyro_response[i],
+                    |r| r > 0.5,
+                )
+            }),
+        },
+    }
+}
+
+impl<DI, T> Mpu6050<DI, T>
+where
+    DI: Interface,
+    T: crate::numeric::NumericType,
+{
+    /// 执行硬件自检
+    ///
+    /// 临时切换量程并开启自检激励，完成后恢复原有量程配置。
+    /// 自检期间设备应保持静止。
+    pub async fn self_test(&mut self) -> Result<SelfTestReport, DI::Error> {
+        let saved_accel = self.config.accel_scale;
+        let saved_gyro = self.config.gyro_scale;
+        let res = self.run_self_test().await;
+        // 无论自检是否成功都尝试恢复量程，优先返回自检本身的错误
+        let accel_restored = self.set_accel_scale(saved_accel).await;
+        let gyro_restored = self.set_gyro_scale(saved_gyro).await;
+        let report = res?;
+        accel_restored?;
+        gyro_restored?;
+        Ok(report)
+    }
+
+    async fn run_self_test(&mut self) -> Result<SelfTestReport, DI::Error> {
+        let (accel_scale, gyro_scale) = self_test_scales(self.chip);
+
+        self.write_register(ACCEL_CONFIG, accel_scale as u8).await?;
+        self.write_register(GYRO_CONFIG, gyro_scale as u8).await?;
+        Timer::after_millis(SELF_TEST_SETTLE_MS as u64).await;
+        let normal = self.average_raw(SELF_TEST_SAMPLES).await?;
+
+        self.write_register(ACCEL_CONFIG, SELF_TEST_ENABLE | accel_scale as u8)
+            .await?;
+        self.write_register(GYRO_CONFIG, SELF_TEST_ENABLE | gyro_scale as u8)
+            .await?;
+        Timer::after_millis(SELF_TEST_SETTLE_MS as u64).await;
+        let excited = self.average_raw(SELF_TEST_SAMPLES).await?;
+
+        let mut factory = [0u8; 6];
+        let mut pos = 0;
+        for &(reg, len) in factory_trim_registers(self.chip) {
+            self.read_registers(reg, &mut factory[pos..pos + len])
+                .await?;
+            pos += len;
+        }
+        Ok(self_test_report(self.chip, normal, excited, factory))
+    }
+
+    /// 多次采样求平均：[ax, ay, az, gx, gy, gz]
+    async fn average_raw(&mut self, samples: u16) -> Result<[f32; 6], DI::Error> {
+        let mut sum = [0f32; 6];
+        for _ in 0..samples {
+            let RawSensorData { accel, gyro, .. } = self.read_all_raw_chip().await?;
+            for (s, v) in sum
+                .iter_mut()
+                .zip([accel.0, accel.1, accel.2, gyro.0, gyro.1, gyro.2])
+            {
+                *s += v as f32;
+            }
+            Timer::after_millis(1).await;
+        }
+        Ok(sum.map(|s| s / samples as f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mpu6050_factory_trim_decoding() {
+        // XA_TEST = 0b10101, XG_TEST = 1；Y/Z 自检值为0
+        let (accel, gyro) = mpu6050_factory_trim([0b1010_0001, 0, 0, 0b0001_0000]);
+        let expected = 4096.0 * 0.34 * libm::powf(0.92 / 0.34, 20.0 / 30.0);
+        assert!((accel[0] - expected).abs() < 1e-2);
+        assert_eq!(accel[1], 0.0);
+        assert!((gyro[0] - 25.0 * 131.0).abs() < 1e-3);
+        assert_eq!(gyro[2], 0.0);
+    }
+
+    #[test]
+    fn test_evaluate_change_percent() {
+        let ok = |r: f32| libm::fabsf(r - 1.0) * 100.0 <= MPU6050_MAX_CHANGE;
+        let axis = evaluate(1000.0, 1100.0, ok);
+        assert!((axis.change_percent - 10.0).abs() < 1e-3);
+        assert!(axis.passed);
+        assert!(!evaluate(1000.0, 1200.0, ok).passed);
+        assert!(!evaluate(0.0, 1000.0, ok).passed);
+    }
+}
@@ -0,0 +1,108 @@
+//! 姿态更新的自动 dt 测量
+
+use crate::Mpu6050;
+use crate::error::Result;
+use crate::fusion;
+use crate::interface::Interface;
+use embassy_time::Instant;
+
+/// 两次更新间隔超过该值（秒）视为停顿，姿态从加速度计重新初始化
+pub const MAX_AUTO_DT: f32 = 0.5;
+
+/// 自动 dt 的判定结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum AutoDt {
+    /// 首次调用：只记录时间戳，不积分
+    Seed,
+    /// 时间未前进（同一时钟节拍内重复调用）：不积分，保留上次时间戳
+    Skip,
+    /// 正常积分，单位秒
+    Integrate(f32),
+    /// 间隔过长，需重新初始化姿态
+    Stalled,
+}
+
+/// 由上一次时间戳计算 dt
+pub(crate) fn auto_dt(last: Option<Instant>, now: Instant) -> AutoDt {
+    let Some(last) = last else {
+        return AutoDt::Seed;
+    };
+    let micros = now
+        .checked_duration_since(last)
+        .map_or(0, |d| d.as_micros());
+    if micros == 0 {
+        return AutoDt::Skip;
+    }
+    let dt = micros as f32 / 1_000_000.0;
+    if dt > MAX_AUTO_DT {
+        AutoDt::Stalled
+    } else {
+        AutoDt::Integrate(dt)
+    }
+}
+
+impl<DI, T> Mpu6050<DI, T>
+where
+    DI: Interface,
+    T: crate::numeric::NumericType,
+{
+    /// 芯片实际输出采样周期（秒），按 DLPF 对应的陀螺仪输出频率与 SMPLRT_DIV 分频计算
+    ///
+    /// FIFO 流式读取时每帧间隔即为该值
+    pub fn sample_period(&self) -> T {
+        T::from_f32(self.config.sample_period())
+    }
+
+    /// 清除上次更新时间戳，下一次 [`update_auto`](Self::update_auto) 按首次调用处理
+    ///
+    /// 睡眠唤醒或暂停采样后应调用
+    pub fn reset_timing(&mut self) {
+        self.last_update = None;
+    }
+
+    /// 自动测量 dt 的姿态更新，返回本次使用的 dt（秒）
+    ///
+    /// - 首次调用只记录时间戳，不读取传感器，返回 0
+    /// - 与上次调用处于同一时钟节拍（时间未前进）时不积分，返回 0，间隔累计到下次调用
+    /// - 间隔超过 [`MAX_AUTO_DT`] 时不积分，直接用加速度计重置 pitch/roll（yaw 保持），返回 0
+    pub async fn update_auto(&mut self, alpha: T) -> Result<T, DI::Error> {
+        let now = Instant::now();
+        let step = auto_dt(self.last_update, now);
+        if step != AutoDt::Skip {
+            self.last_update = Some(now);
+        }
+        match step {
+            AutoDt::Seed | AutoDt::Skip => Ok(T::zero()),
+            AutoDt::Integrate(dt) => {
+                let dt = T::from_f32(dt);
+                self.update(dt, alpha).await?;
+                Ok(dt)
+            }
+            AutoDt::Stalled => {
+                let accel = self.read_accel().await?;
+                (self.pitch, self.roll) = fusion::pitch_roll_from_accel(accel);
+                Ok(T::zero())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_dt() {
+        let t0 = Instant::from_micros(1_000_000);
+        assert_eq!(auto_dt(None, t0), AutoDt::Seed);
+        assert_eq!(auto_dt(Some(t0), t0), AutoDt::Skip);
+        assert_eq!(
+            auto_dt(Some(t0), Instant::from_micros(1_010_000)),
+            AutoDt::Integrate(0.01)
+        );
+        assert_eq!(
+            auto_dt(Some(t0), Instant::from_micros(2_000_000)),
+            AutoDt::Stalled
+        );
+    }
+}
@@ -1,20 +1,26 @@
 //! 错误类型定义
-//! 
+//!
 //! 提供统一的错误处理机制
 
 use core::fmt;
 
 /// MPU6050库的统一错误类型
 #[derive(Debug, Clone, PartialEq)]
-pub enum Mpu6050Error<SpiError> {
-    /// SPI通信错误
-    Spi(SpiError),
+pub enum Mpu6050Error<BusError> {
+    /// 总线通信错误（SPI/I2C）
+    Bus(BusError),
+    /// 片选引脚操作失败
+    ChipSelect,
+    /// 等待设备响应超时
+    Timeout,
     /// 设备未找到或ID不匹配
     DeviceNotFound,
     /// 配置无效
     InvalidConfig,
     /// 校准失败
     CalibrationFailed,
+    /// 标定档案无效（长度、版本或校验错误）
+    InvalidProfile,
     /// 数据读取失败
     DataReadFailed,
     /// FIFO错误
@@ -25,16 +31,19 @@ pub enum Mpu6050Error<SpiError> {
     ConversionError,
 }
 
-impl<SpiError> fmt::Display for Mpu6050Error<SpiError>
+impl<BusError> fmt::Display for Mpu6050Error<BusError>
 where
-    SpiError: fmt::Display,
+    BusError: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Mpu6050Error::Spi(e) => write!(f, "SPI error: {e}"),
+            Mpu6050Error::Bus(e) => write!(f, "Bus error: {e}"),
+            Mpu6050Error::ChipSelect => write!(f, "Chip-select pin error"),
+            Mpu6050Error::Timeout => write!(f, "Timed out waiting for device"),
             Mpu6050Error::DeviceNotFound => write!(f, "MPU6050 device not found"),
             Mpu6050Error::InvalidConfig => write!(f, "Invalid configuration"),
             Mpu6050Error::CalibrationFailed => write!(f, "Sensor calibration failed"),
+            Mpu6050Error::InvalidProfile => write!(f, "Invalid calibration profile"),
             Mpu6050Error::DataReadFailed => write!(f, "Failed to read sensor data"),
             Mpu6050Error::FifoError => write!(f, "FIFO operation error"),
             Mpu6050Error::InterruptError => write!(f, "Interrupt configuration error"),
@@ -43,14 +52,14 @@ where
     }
 }
 
-impl<SpiError> From<SpiError> for Mpu6050Error<SpiError> {
-    fn from(error: SpiError) -> Self {
-        Mpu6050Error::Spi(error)
+impl<BusError> From<BusError> for Mpu6050Error<BusError> {
+    fn from(error: BusError) -> Self {
+        Mpu6050Error::Bus(error)
     }
 }
 
 /// 结果类型别名
-pub type Result<T, SpiError> = core::result::Result<T, Mpu6050Error<SpiError>>;
+pub type Result<T, BusError> = core::result::Result<T, Mpu6050Error<BusError>>;
 
 /// 配置验证trait
 pub trait ConfigValidation {
@@ -59,8 +68,7 @@ pub trait ConfigValidation {
 }
 
 /// 设备状态枚举
-#[derive(Debug, Clone, Copy, PartialEq)]
-#[derive(Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum DeviceStatus {
     /// 未初始化
     #[default]
@@ -73,7 +81,6 @@ pub enum DeviceStatus {
     Error,
 }
 
-
 /// 传感器类型枚举
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SensorType {
@@ -108,12 +115,12 @@ impl SensorStatus {
             last_update: None,
         }
     }
-    
+
     /// 标记为已校准
     pub fn mark_calibrated(&mut self) {
         self.calibrated = true;
     }
-    
+
     /// 更新时间戳
     pub fn update_timestamp(&mut self, timestamp: u64) {
         self.last_update = Some(timestamp);
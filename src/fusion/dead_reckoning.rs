@@ -0,0 +1,142 @@
+//! 捷联惯导航位推算与零速修正（ZUPT）
+
+use super::LinearAcceleration;
+use crate::numeric::NumericType;
+
+/// 静止检测阈值
+#[derive(Debug, Clone, Copy)]
+pub struct ZuptConfig<T: NumericType> {
+    /// 世界系线性加速度模长上限（m/s²）
+    pub accel_threshold: T,
+    /// 角速度模长上限（rad/s）
+    pub gyro_threshold: T,
+    /// 连续满足阈值的样本数，达到后判定为静止
+    pub min_still_samples: u16,
+}
+
+impl<T: NumericType> Default for ZuptConfig<T> {
+    fn default() -> Self {
+        Self {
+            accel_threshold: T::from_f32(0.3),
+            gyro_threshold: T::from_f32(0.1),
+            min_still_samples: 10,
+        }
+    }
+}
+
+/// 速度与位移积分器，静止时将速度清零以抑制漂移
+#[derive(Debug, Clone)]
+pub struct DeadReckoning<T: NumericType = f32> {
+    config: ZuptConfig<T>,
+    velocity: (T, T, T),
+    position: (T, T, T),
+    still_count: u16,
+    stationary: bool,
+}
+
+impl<T: NumericType> DeadReckoning<T> {
+    /// 创建积分器，初始速度与位移为0
+    pub fn new(config: ZuptConfig<T>) -> Self {
+        Self {
+            config,
+            velocity: (T::zero(), T::zero(), T::zero()),
+            position: (T::zero(), T::zero(), T::zero()),
+            still_count: 0,
+            stationary: false,
+        }
+    }
+
+    /// 修改静止检测阈值
+    pub fn set_config(&mut self, config: ZuptConfig<T>) {
+        self.config = config;
+    }
+
+    /// 清零速度、位移和静止状态
+    pub fn reset(&mut self) {
+        *self = Self::new(self.config);
+    }
+
+    /// 世界系速度（m/s）
+    pub fn velocity(&self) -> (T, T, T) {
+        self.velocity
+    }
+
+    /// 世界系位移（m），相对创建或 [`reset`](Self::reset) 时的位置
+    pub fn position(&self) -> (T, T, T) {
+        self.position
+    }
+
+    /// 当前是否判定为静止
+    pub fn is_stationary(&self) -> bool {
+        self.stationary
+    }
+
+    /// 输入一帧线性加速度与角速度（rad/s）进行积分
+    pub fn update(&mut self, linear: &LinearAcceleration<T>, gyro: (T, T, T), dt: T) {
+        let (ax, ay, az) = linear.world;
+        let (gx, gy, gz) = gyro;
+        let accel_norm = (ax * ax + ay * ay + az * az).sqrt();
+        let gyro_norm = (gx * gx + gy * gy + gz * gz).sqrt();
+
+        if accel_norm < self.config.accel_threshold && gyro_norm < self.config.gyro_threshold {
+            self.still_count = self.still_count.saturating_add(1);
+        } else {
+            self.still_count = 0;
+        }
+        self.stationary = self.still_count >= self.config.min_still_samples;
+
+        if self.stationary {
+            self.velocity = (T::zero(), T::zero(), T::zero());
+            return;
+        }
+
+        let (vx, vy, vz) = self.velocity;
+        self.velocity = (vx + ax * dt, vy + ay * dt, vz + az * dt);
+        let (vx, vy, vz) = self.velocity;
+        let (px, py, pz) = self.position;
+        self.position = (px + vx * dt, py + vy * dt, pz + vz * dt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear(ax: f32) -> LinearAcceleration<f32> {
+        LinearAcceleration {
+            body: (ax, 0.0, 0.0),
+            world: (ax, 0.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_integrates_motion() {
+        let mut dr = DeadReckoning::new(ZuptConfig::default());
+        for _ in 0..100 {
+            dr.update(&linear(1.0), (0.0, 0.0, 0.0), 0.01);
+        }
+        assert!(!dr.is_stationary());
+        assert!((dr.velocity().0 - 1.0).abs() < 1e-4);
+        assert!((dr.position().0 - 0.505).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_zupt_clears_velocity() {
+        let mut dr = DeadReckoning::new(ZuptConfig::default());
+        for _ in 0..50 {
+            dr.update(&linear(1.0), (0.0, 0.0, 0.0), 0.01);
+        }
+        let drift = dr.velocity().0;
+        for _ in 0..9 {
+            dr.update(&linear(0.0), (0.0, 0.0, 0.0), 0.01);
+        }
+        assert!(!dr.is_stationary());
+        assert_eq!(dr.velocity().0, drift);
+        dr.update(&linear(0.0), (0.0, 0.0, 0.0), 0.01);
+        assert!(dr.is_stationary());
+        assert_eq!(dr.velocity(), (0.0, 0.0, 0.0));
+        // 转动时不判定静止
+        dr.update(&linear(0.0), (0.0, 0.0, 1.0), 0.01);
+        assert!(!dr.is_stationary());
+    }
+}
@@ -0,0 +1,353 @@
+//! 扩展卡尔曼滤波（EKF）姿态与陀螺仪零偏估计
+//!
+//! 状态量为四元数（4维）与三轴陀螺仪零偏（3维），全部使用定长数组，无堆分配。
+//! 陀螺仪驱动预测，加速度计方向作为观测；加速度模长偏离 1g 超过阈值时拒绝观测。
+
+// 矩阵运算按下标书写更贴近公式
+#![allow(clippy::needless_range_loop)]
+
+use super::Quaternion;
+use crate::SensorData;
+use crate::numeric::NumericType;
+use crate::util::inverse3;
+
+/// 状态维数
+const N: usize = 7;
+
+/// EKF 噪声与门限配置
+#[derive(Debug, Clone, Copy)]
+pub struct EkfConfig<T: NumericType> {
+    /// 陀螺仪噪声方差（(rad/s)²）
+    pub gyro_noise: T,
+    /// 零偏随机游走方差（(rad/s)²/s）
+    pub bias_noise: T,
+    /// 加速度计方向观测噪声方差（归一化单位）
+    pub accel_noise: T,
+    /// 加速度模长允许偏离 1g 的比例，如 0.1 表示 0.9g~1.1g
+    pub accel_gate: T,
+    /// 重力加速度，与加速度计读数单位一致（`read_accel` 为 m/s²）
+    pub gravity: T,
+    /// 初始四元数方差
+    pub initial_attitude_variance: T,
+    /// 初始零偏方差
+    pub initial_bias_variance: T,
+}
+
+impl<T: NumericType> Default for EkfConfig<T> {
+    fn default() -> Self {
+        Self {
+            gyro_noise: T::from_f32(1e-4),
+            bias_noise: T::from_f32(1e-8),
+            accel_noise: T::from_f32(1e-2),
+            accel_gate: T::from_f32(0.1),
+            gravity: T::from_f32(9.81),
+            initial_attitude_variance: T::from_f32(1e-2),
+            initial_bias_variance: T::from_f32(1e-4),
+        }
+    }
+}
+
+/// 姿态 EKF
+#[derive(Debug, Clone)]
+pub struct AttitudeEkf<T: NumericType = f32> {
+    config: EkfConfig<T>,
+    q: Quaternion<T>,
+    bias: [T; 3],
+    p: [[T; N]; N],
+    accel_rejected: bool,
+}
+
+impl<T: NumericType> AttitudeEkf<T> {
+    /// 创建滤波器，初始姿态为单位四元数、零偏为0
+    pub fn new(config: EkfConfig<T>) -> Self {
+        let mut ekf = Self {
+            config,
+            q: Quaternion::identity(),
+            bias: [T::zero(); 3],
+            p: [[T::zero(); N]; N],
+            accel_rejected: false,
+        };
+        ekf.reset_covariance();
+        ekf
+    }
+
+    /// 根据静止时的加速度计读数重置姿态（yaw 置零）、零偏和协方差
+    pub fn reset_from_accel(&mut self, accel: (T, T, T)) {
+        if let Some(q) = Quaternion::from_gravity(accel) {
+            self.q = q;
+        }
+        self.bias = [T::zero(); 3];
+        self.reset_covariance();
+    }
+
+    fn reset_covariance(&mut self) {
+        self.p = [[T::zero(); N]; N];
+        for i in 0..4 {
+            self.p[i][i] = self.config.initial_attitude_variance;
+        }
+        for i in 4..N {
+            self.p[i][i] = self.config.initial_bias_variance;
+        }
+    }
+
+    /// 当前姿态四元数
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.q
+    }
+
+    /// 当前姿态旋转矩阵（机体系 → 世界系）
+    pub fn rotation_matrix(&self) -> [[T; 3]; 3] {
+        self.q.to_rotation_matrix()
+    }
+
+    /// 当前欧拉角 `(pitch, roll, yaw)`（弧度）
+    pub fn euler_angles(&self) -> (T, T, T) {
+        self.q.to_euler()
+    }
+
+    /// 当前零偏估计（rad/s）
+    pub fn gyro_bias(&self) -> (T, T, T) {
+        (self.bias[0], self.bias[1], self.bias[2])
+    }
+
+    /// 状态协方差矩阵，顺序为 `[q0, q1, q2, q3, bx, by, bz]`
+    pub fn covariance(&self) -> &[[T; N]; N] {
+        &self.p
+    }
+
+    /// 零偏估计的标准差（rad/s）
+    pub fn gyro_bias_std(&self) -> (T, T, T) {
+        (
+            self.p[4][4].sqrt(),
+            self.p[5][5].sqrt(),
+            self.p[6][6].sqrt(),
+        )
+    }
+
+    /// 上一次更新是否因加速度模长超限而跳过观测
+    pub fn accel_rejected(&self) -> bool {
+        self.accel_rejected
+    }
+
+    /// 使用一帧传感器数据更新（加速度 m/s²，角速度 rad/s）
+    pub fn update(&mut self, data: &SensorData<T>, dt: T) {
+        self.predict(data.gyro, dt);
+        self.correct(data.accel);
+    }
+
+    /// 预测步：陀螺仪角速度（rad/s）积分
+    pub fn predict(&mut self, gyro: (T, T, T), dt: T) {
+        let half_dt = T::from_f32(0.5) * dt;
+        let w = [
+            gyro.0 - self.bias[0],
+            gyro.1 - self.bias[1],
+            gyro.2 - self.bias[2],
+        ];
+        let q = [self.q.w, self.q.x, self.q.y, self.q.z];
+        // q ⊗ (0, ω) = Ξ(q)·ω
+        let xi = [
+            [-q[1], -q[2], -q[3]],
+            [q[0], -q[3], q[2]],
+            [q[3], q[0], -q[1]],
+            [-q[2], q[1], q[0]],
+        ];
+        // q ⊗ (0, ω) = Ω(ω)·q
+        let omega = [
+            [T::zero(), -w[0], -w[1], -w[2]],
+            [w[0], T::zero(), w[2], -w[1]],
+            [w[1], -w[2], T::zero(), w[0]],
+            [w[2], w[1], -w[0], T::zero()],
+        ];
+
+        let mut f = identity::<T>();
+        for i in 0..4 {
+            for j in 0..4 {
+                f[i][j] += half_dt * omega[i][j];
+            }
+            for j in 0..3 {
+                f[i][4 + j] = -half_dt * xi[i][j];
+            }
+        }
+
+        let mut q_new = [T::zero(); 4];
+        for (i, qi) in q_new.iter_mut().enumerate() {
+            for j in 0..4 {
+                *qi += f[i][j] * q[j];
+            }
+        }
+        self.q = Quaternion::new(q_new[0], q_new[1], q_new[2], q_new[3]).normalize();
+
+        // P = F·P·Fᵀ + Q
+        let fp = mat_mul(&f, &self.p);
+        let mut p = [[T::zero(); N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                for k in 0..N {
+                    p[i][j] += fp[i][k] * f[j][k];
+                }
+            }
+        }
+        let gyro_q = self.config.gyro_noise * half_dt * half_dt;
+        for i in 0..4 {
+            for j in 0..4 {
+                let mut xx = T::zero();
+                for k in 0..3 {
+                    xx += xi[i][k] * xi[j][k];
+                }
+                p[i][j] += gyro_q * xx;
+            }
+        }
+        for (i, row) in p.iter_mut().enumerate().skip(4) {
+            row[i] += self.config.bias_noise * dt;
+        }
+        self.p = p;
+    }
+
+    /// 观测步：加速度计方向修正姿态与零偏
+    ///
+    /// 模长偏离 1g 超过 `accel_gate` 时跳过并置位 [`accel_rejected`](Self::accel_rejected)
+    pub fn correct(&mut self, accel: (T, T, T)) {
+        let (ax, ay, az) = accel;
+        let norm = (ax * ax + ay * ay + az * az).sqrt();
+        let deviation = (norm / self.config.gravity - T::one()).abs();
+        self.accel_rejected = norm == T::zero() || deviation > self.config.accel_gate;
+        if self.accel_rejected {
+            return;
+        }
+        let z = [ax / norm, ay / norm, az / norm];
+
+        let two = T::from_f32(2.0);
+        let q = [self.q.w, self.q.x, self.q.y, self.q.z];
+        // 预测的重力方向 h(q) = Rᵀ·[0, 0, 1]
+        let h = [
+            two * (q[1] * q[3] - q[0] * q[2]),
+            two * (q[0] * q[1] + q[2] * q[3]),
+            q[0] * q[0] - q[1] * q[1] - q[2] * q[2] + q[3] * q[3],
+        ];
+        let mut hj = [[T::zero(); N]; 3];
+        hj[0][..4].copy_from_slice(&[-two * q[2], two * q[3], -two * q[0], two * q[1]]);
+        hj[1][..4].copy_from_slice(&[two * q[1], two * q[0], two * q[3], two * q[2]]);
+        hj[2][..4].copy_from_slice(&[two * q[0], -two * q[1], -two * q[2], two * q[3]]);
+
+        // P·Hᵀ (N×3)
+        let mut pht = [[T::zero(); 3]; N];
+        for i in 0..N {
+            for j in 0..3 {
+                for k in 0..N {
+                    pht[i][j] += self.p[i][k] * hj[j][k];
+                }
+            }
+        }
+        // S = H·P·Hᵀ + R
+        let mut s = [[T::zero(); 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..N {
+                    s[i][j] += hj[i][k] * pht[k][j];
+                }
+            }
+            s[i][i] += self.config.accel_noise;
+        }
+        let Some(s_inv) = inverse3(&s, T::zero()) else {
+            return;
+        };
+        // K = P·Hᵀ·S⁻¹ (N×3)
+        let mut k = [[T::zero(); 3]; N];
+        for i in 0..N {
+            for j in 0..3 {
+                for m in 0..3 {
+                    k[i][j] += pht[i][m] * s_inv[m][j];
+                }
+            }
+        }
+
+        let y = [z[0] - h[0], z[1] - h[1], z[2] - h[2]];
+        let mut dx = [T::zero(); N];
+        for (i, d) in dx.iter_mut().enumerate() {
+            for j in 0..3 {
+                *d += k[i][j] * y[j];
+            }
+        }
+        self.q =
+            Quaternion::new(q[0] + dx[0], q[1] + dx[1], q[2] + dx[2], q[3] + dx[3]).normalize();
+        for i in 0..3 {
+            self.bias[i] += dx[4 + i];
+        }
+
+        // P = (I - K·H)·P
+        let mut ikh = identity::<T>();
+        for i in 0..N {
+            for j in 0..N {
+                for m in 0..3 {
+                    ikh[i][j] -= k[i][m] * hj[m][j];
+                }
+            }
+        }
+        let mut p = mat_mul(&ikh, &self.p);
+        // 保持对称
+        let half = T::from_f32(0.5);
+        for i in 0..N {
+            for j in (i + 1)..N {
+                let v = half * (p[i][j] + p[j][i]);
+                p[i][j] = v;
+                p[j][i] = v;
+            }
+        }
+        self.p = p;
+    }
+}
+
+fn identity<T: NumericType>() -> [[T; N]; N] {
+    let mut m = [[T::zero(); N]; N];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
+    m
+}
+
+fn mat_mul<T: NumericType>(a: &[[T; N]; N], b: &[[T; N]; N]) -> [[T; N]; N] {
+    let mut m = [[T::zero(); N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            for k in 0..N {
+                m[i][j] += a[i][k] * b[k][j];
+            }
+        }
+    }
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converges_and_estimates_bias() {
+        let mut ekf = AttitudeEkf::<f32>::new(EkfConfig::default());
+        let roll = 0.3f32;
+        let accel = (0.0, 9.81 * libm::sinf(roll), 9.81 * libm::cosf(roll));
+        let bias = (0.01f32, -0.02f32, 0.0f32);
+        for _ in 0..5000 {
+            ekf.predict(bias, 0.01);
+            ekf.correct(accel);
+        }
+        let (pitch, est_roll, _) = ekf.euler_angles();
+        assert!((est_roll - roll).abs() < 0.01);
+        assert!(pitch.abs() < 0.01);
+        let (bx, by, _) = ekf.gyro_bias();
+        assert!((bx - bias.0).abs() < 2e-3);
+        assert!((by - bias.1).abs() < 2e-3);
+        assert!(!ekf.accel_rejected());
+    }
+
+    #[test]
+    fn test_rejects_accel_far_from_one_g() {
+        let mut ekf = AttitudeEkf::<f32>::new(EkfConfig::default());
+        let before = ekf.covariance()[1][1];
+        ekf.predict((0.0, 0.0, 0.0), 0.01);
+        ekf.correct((0.0, 9.81, 9.81));
+        assert!(ekf.accel_rejected());
+        assert_eq!(ekf.quaternion(), Quaternion::identity());
+        assert!(ekf.covariance()[1][1] >= before);
+    }
+}
@@ -0,0 +1,65 @@
+//! 去除重力后的线性加速度
+
+use super::Quaternion;
+use crate::numeric::NumericType;
+
+/// 线性加速度（已去除重力），单位与输入一致
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LinearAcceleration<T: NumericType> {
+    /// 机体系
+    pub body: (T, T, T),
+    /// 世界系（z 轴向上）
+    pub world: (T, T, T),
+}
+
+/// 根据姿态从加速度计读数中去除重力
+///
+/// - `attitude`: 机体系 → 世界系的姿态四元数
+/// - `accel`: 加速度计读数（比力），静止水平时为 `(0, 0, g)`
+/// - `gravity`: 重力加速度，与 `accel` 单位一致
+pub fn linear_acceleration<T: NumericType>(
+    attitude: &Quaternion<T>,
+    accel: (T, T, T),
+    gravity: T,
+) -> LinearAcceleration<T> {
+    let (wx, wy, wz) = attitude.rotate_vector(accel);
+    let (gx, gy, gz) = attitude
+        .conjugate()
+        .rotate_vector((T::zero(), T::zero(), gravity));
+    LinearAcceleration {
+        body: (accel.0 - gx, accel.1 - gy, accel.2 - gz),
+        world: (wx, wy, wz - gravity),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_static_tilt_has_no_linear_accel() {
+        let q = Quaternion::from_euler(0.3f32, -0.5, 1.0);
+        let accel = q.conjugate().rotate_vector((0.0, 0.0, 9.81));
+        let lin = linear_acceleration(&q, accel, 9.81);
+        for v in [
+            lin.body.0,
+            lin.body.1,
+            lin.body.2,
+            lin.world.0,
+            lin.world.1,
+            lin.world.2,
+        ] {
+            assert!(v.abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_world_frame_rotation() {
+        // yaw 90°：机体 x 方向的 1 m/s² 对应世界 y 方向
+        let q = Quaternion::from_euler(0.0f32, 0.0, core::f32::consts::FRAC_PI_2);
+        let lin = linear_acceleration(&q, (1.0, 0.0, 9.81), 9.81);
+        assert!((lin.body.0 - 1.0).abs() < 1e-5 && lin.body.2.abs() < 1e-5);
+        assert!(lin.world.0.abs() < 1e-5 && (lin.world.1 - 1.0).abs() < 1e-5);
+        assert!(lin.world.2.abs() < 1e-5);
+    }
+}
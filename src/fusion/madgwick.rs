@@ -0,0 +1,144 @@
+//! Madgwick 梯度下降 AHRS 滤波器
+//!
+//! 参考 S. Madgwick, "An efficient orientation filter for inertial and
+//! inertial/magnetic sensor arrays", 2010（IMU版本，无磁力计）
+
+use super::Quaternion;
+use crate::SensorData;
+use crate::numeric::NumericType;
+
+/// Madgwick 滤波器
+#[derive(Debug, Clone)]
+pub struct Madgwick<T: NumericType> {
+    /// 梯度下降步长，越大越信任加速度计（典型值 0.03~0.3）
+    beta: T,
+    q: Quaternion<T>,
+}
+
+impl<T: NumericType> Madgwick<T> {
+    /// 创建滤波器，初始姿态为单位四元数
+    pub fn new(beta: T) -> Self {
+        Self {
+            beta,
+            q: Quaternion::identity(),
+        }
+    }
+
+    /// 设置梯度下降步长
+    pub fn set_beta(&mut self, beta: T) {
+        self.beta = beta;
+    }
+
+    /// 设置当前姿态
+    pub fn set_quaternion(&mut self, q: Quaternion<T>) {
+        self.q = q.normalize();
+    }
+
+    /// 当前姿态四元数
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.q
+    }
+
+    /// 当前姿态旋转矩阵（机体系 → 世界系）
+    pub fn rotation_matrix(&self) -> [[T; 3]; 3] {
+        self.q.to_rotation_matrix()
+    }
+
+    /// 当前欧拉角 `(pitch, roll, yaw)`（弧度）
+    pub fn euler_angles(&self) -> (T, T, T) {
+        self.q.to_euler()
+    }
+
+    /// 使用一帧传感器数据更新
+    pub fn update(&mut self, data: &SensorData<T>, dt: T) {
+        self.update_imu(data.gyro, data.accel, dt);
+    }
+
+    /// 使用陀螺仪（rad/s）和加速度计（任意单位）更新姿态
+    ///
+    /// 加速度计读数为零向量时仅进行陀螺仪积分
+    pub fn update_imu(&mut self, gyro: (T, T, T), accel: (T, T, T), dt: T) {
+        let half = T::from_f32(0.5);
+        let two = T::from_f32(2.0);
+        let four = T::from_f32(4.0);
+        let eight = T::from_f32(8.0);
+        let Quaternion {
+            w: q0,
+            x: q1,
+            y: q2,
+            z: q3,
+        } = self.q;
+        let (gx, gy, gz) = gyro;
+
+        // 陀螺仪角速度导出的四元数变化率
+        let mut q_dot0 = half * (-q1 * gx - q2 * gy - q3 * gz);
+        let mut q_dot1 = half * (q0 * gx + q2 * gz - q3 * gy);
+        let mut q_dot2 = half * (q0 * gy - q1 * gz + q3 * gx);
+        let mut q_dot3 = half * (q0 * gz + q1 * gy - q2 * gx);
+
+        let (ax, ay, az) = accel;
+        let a_norm = (ax * ax + ay * ay + az * az).sqrt();
+        if a_norm != T::zero() {
+            let (ax, ay, az) = (ax / a_norm, ay / a_norm, az / a_norm);
+
+            let (q0q0, q1q1, q2q2, q3q3) = (q0 * q0, q1 * q1, q2 * q2, q3 * q3);
+            // 目标函数梯度
+            let s0 = four * q0 * q2q2 + two * q2 * ax + four * q0 * q1q1 - two * q1 * ay;
+            let s1 =
+                four * q1 * q3q3 - two * q3 * ax + four * q0q0 * q1 - two * q0 * ay - four * q1
+                    + eight * q1 * q1q1
+                    + eight * q1 * q2q2
+                    + four * q1 * az;
+            let s2 =
+                four * q0q0 * q2 + two * q0 * ax + four * q2 * q3q3 - two * q3 * ay - four * q2
+                    + eight * q2 * q1q1
+                    + eight * q2 * q2q2
+                    + four * q2 * az;
+            let s3 = four * q1q1 * q3 - two * q1 * ax + four * q2q2 * q3 - two * q2 * ay;
+
+            let s_norm = (s0 * s0 + s1 * s1 + s2 * s2 + s3 * s3).sqrt();
+            if s_norm != T::zero() {
+                q_dot0 -= self.beta * s0 / s_norm;
+                q_dot1 -= self.beta * s1 / s_norm;
+                q_dot2 -= self.beta * s2 / s_norm;
+                q_dot3 -= self.beta * s3 / s_norm;
+            }
+        }
+
+        self.q = Quaternion::new(
+            q0 + q_dot0 * dt,
+            q1 + q_dot1 * dt,
+            q2 + q_dot2 * dt,
+            q3 + q_dot3 * dt,
+        )
+        .normalize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converges_to_accel_tilt() {
+        let mut filter = Madgwick::<f32>::new(0.5);
+        let roll = 0.3f32;
+        let accel = (0.0, libm::sinf(roll), libm::cosf(roll));
+        for _ in 0..2000 {
+            filter.update_imu((0.0, 0.0, 0.0), accel, 0.01);
+        }
+        let (pitch, est_roll, _) = filter.euler_angles();
+        assert!((est_roll - roll).abs() < 0.01);
+        assert!(pitch.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gyro_integration_without_accel() {
+        let mut filter = Madgwick::<f32>::new(0.1);
+        for _ in 0..100 {
+            filter.update_imu((0.0, 0.0, 0.5), (0.0, 0.0, 0.0), 0.01);
+        }
+        let (_, _, yaw) = filter.euler_angles();
+        assert!((yaw - 0.5).abs() < 1e-3);
+    }
+}
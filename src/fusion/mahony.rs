@@ -0,0 +1,170 @@
+//! Mahony 互补 AHRS 滤波器
+//!
+//! 比例项修正姿态误差，积分项在线估计陀螺仪零偏。
+//! 参考 R. Mahony et al., "Nonlinear Complementary Filters on the Special
+//! Orthogonal Group", 2008（IMU版本，无磁力计）
+
+use super::Quaternion;
+use crate::SensorData;
+use crate::numeric::NumericType;
+
+/// Mahony 滤波器
+#[derive(Debug, Clone)]
+pub struct Mahony<T: NumericType> {
+    /// 比例增益
+    kp: T,
+    /// 积分增益，为0时不估计零偏
+    ki: T,
+    /// 误差积分（即零偏估计的相反数，rad/s）
+    integral: (T, T, T),
+    q: Quaternion<T>,
+}
+
+impl<T: NumericType> Mahony<T> {
+    /// 创建滤波器，初始姿态为单位四元数
+    ///
+    /// 典型参数：`kp = 1.0`，`ki = 0.01~0.1`
+    pub fn new(kp: T, ki: T) -> Self {
+        Self {
+            kp,
+            ki,
+            integral: (T::zero(), T::zero(), T::zero()),
+            q: Quaternion::identity(),
+        }
+    }
+
+    /// 设置比例和积分增益
+    pub fn set_gains(&mut self, kp: T, ki: T) {
+        self.kp = kp;
+        self.ki = ki;
+    }
+
+    /// 当前零偏估计（rad/s）
+    ///
+    /// 无磁力计时 Z 轴零偏不可观测，仅 X/Y 轴收敛
+    pub fn gyro_bias(&self) -> (T, T, T) {
+        (-self.integral.0, -self.integral.1, -self.integral.2)
+    }
+
+    /// 当前姿态四元数
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.q
+    }
+
+    /// 当前姿态旋转矩阵（机体系 → 世界系）
+    pub fn rotation_matrix(&self) -> [[T; 3]; 3] {
+        self.q.to_rotation_matrix()
+    }
+
+    /// 当前欧拉角 `(pitch, roll, yaw)`（弧度）
+    pub fn euler_angles(&self) -> (T, T, T) {
+        self.q.to_euler()
+    }
+
+    /// 根据加速度计读数重置姿态（yaw 置零）并清除零偏估计
+    ///
+    /// 设备需静止，加速度计读数为零向量时仅清除零偏估计
+    pub fn reset_from_accel(&mut self, accel: (T, T, T)) {
+        self.integral = (T::zero(), T::zero(), T::zero());
+        if let Some(q) = Quaternion::from_gravity(accel) {
+            self.q = q;
+        }
+    }
+
+    /// 使用一帧传感器数据更新
+    pub fn update(&mut self, data: &SensorData<T>, dt: T) {
+        self.update_imu(data.gyro, data.accel, dt);
+    }
+
+    /// 使用陀螺仪（rad/s）和加速度计（任意单位）更新姿态
+    ///
+    /// 加速度计读数为零向量时仅进行陀螺仪积分
+    pub fn update_imu(&mut self, gyro: (T, T, T), accel: (T, T, T), dt: T) {
+        let half = T::from_f32(0.5);
+        let two = T::from_f32(2.0);
+        let Quaternion {
+            w: q0,
+            x: q1,
+            y: q2,
+            z: q3,
+        } = self.q;
+        let (mut gx, mut gy, mut gz) = gyro;
+
+        let (ax, ay, az) = accel;
+        let a_norm = (ax * ax + ay * ay + az * az).sqrt();
+        if a_norm != T::zero() {
+            let (ax, ay, az) = (ax / a_norm, ay / a_norm, az / a_norm);
+
+            // 当前姿态下重力方向的估计
+            let vx = two * (q1 * q3 - q0 * q2);
+            let vy = two * (q0 * q1 + q2 * q3);
+            let vz = q0 * q0 - q1 * q1 - q2 * q2 + q3 * q3;
+
+            // 误差为测量与估计方向的叉积
+            let ex = ay * vz - az * vy;
+            let ey = az * vx - ax * vz;
+            let ez = ax * vy - ay * vx;
+
+            if self.ki > T::zero() {
+                self.integral.0 += self.ki * ex * dt;
+                self.integral.1 += self.ki * ey * dt;
+                self.integral.2 += self.ki * ez * dt;
+                gx += self.integral.0;
+                gy += self.integral.1;
+                gz += self.integral.2;
+            } else {
+                self.integral = (T::zero(), T::zero(), T::zero());
+            }
+
+            gx += self.kp * ex;
+            gy += self.kp * ey;
+            gz += self.kp * ez;
+        }
+
+        let (gx, gy, gz) = (gx * half * dt, gy * half * dt, gz * half * dt);
+        self.q = Quaternion::new(
+            q0 - q1 * gx - q2 * gy - q3 * gz,
+            q1 + q0 * gx + q2 * gz - q3 * gy,
+            q2 + q0 * gy - q1 * gz + q3 * gx,
+            q3 + q0 * gz + q1 * gy - q2 * gx,
+        )
+        .normalize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimates_gyro_bias() {
+        let mut filter = Mahony::<f32>::new(1.0, 0.1);
+        let bias = (0.02f32, -0.01f32, 0.0f32);
+        for _ in 0..20000 {
+            filter.update_imu(bias, (0.0, 0.0, 9.81), 0.005);
+        }
+        let (bx, by, _) = filter.gyro_bias();
+        assert!((bx - bias.0).abs() < 1e-3);
+        assert!((by - bias.1).abs() < 1e-3);
+        let (pitch, roll, _) = filter.euler_angles();
+        assert!(pitch.abs() < 0.01 && roll.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_reset_from_accel() {
+        let mut filter = Mahony::<f32>::new(1.0, 0.1);
+        let (pitch, roll) = (0.2f32, -0.4f32);
+        // 静止时加速度计测得 (sinθ, sinφ·cosθ, cosφ·cosθ)
+        let accel = (
+            libm::sinf(pitch),
+            libm::sinf(roll) * libm::cosf(pitch),
+            libm::cosf(roll) * libm::cosf(pitch),
+        );
+        filter.reset_from_accel(accel);
+        let (est_pitch, est_roll, yaw) = filter.euler_angles();
+        assert!((est_pitch - pitch).abs() < 1e-5);
+        assert!((est_roll - roll).abs() < 1e-5);
+        assert!(yaw.abs() < 1e-5);
+        assert_eq!(filter.gyro_bias(), (0.0, 0.0, 0.0));
+    }
+}
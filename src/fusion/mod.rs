@@ -0,0 +1,219 @@
+//! 姿态解算算法
+//!
+//! 纯计算实现，不依赖总线，异步与阻塞驱动共用
+//!
+//! # 坐标系约定
+//!
+//! 机体系即芯片坐标系，按 FLU（x 前、y 左、z 上，右手系）理解，世界系 z 轴向上。
+//! 欧拉角采用 ZYX 旋转顺序，姿态表示机体系 → 世界系的旋转，
+//! 依次为 yaw(ψ, 绕 z)、pitch(θ, 绕 y)、roll(φ, 绕 x)：
+//!
+//! - roll 为正：右侧（−y）向下，即绕 +x 的右手旋转
+//! - pitch 为正：机头（+x）向上，与航空约定一致；
+//!   FLU 下绕 +y 的右手旋转使机头向下，因此 θ 取该旋转角的相反数
+//! - yaw 为正：俯视逆时针（向左转），即绕 +z 的右手旋转
+//!
+//! 陀螺仪 gx/gy/gz 分别是绕机体 x/y/z 轴的角速度 `(p, q, r)`，gy 为正时 pitch 减小。
+//! 欧拉角元组顺序为 `(pitch, roll, yaw)`，单位弧度。
+
+mod dead_reckoning;
+mod ekf;
+mod linear;
+mod madgwick;
+mod mahony;
+mod quaternion;
+
+pub use dead_reckoning::{DeadReckoning, ZuptConfig};
+pub use ekf::{AttitudeEkf, EkfConfig};
+pub use linear::{LinearAcceleration, linear_acceleration};
+pub use madgwick::Madgwick;
+pub use mahony::Mahony;
+pub use quaternion::Quaternion;
+
+use crate::numeric::NumericType;
+
+/// 俯仰角 cos 下限，避免 ±90° 附近欧拉角速率发散
+const MIN_COS_PITCH: f32 = 1e-3;
+
+/// 将角度包裹到 `(-π, π]`（弧度）
+///
+/// 按整周期一次扣除，耗时与输入大小无关；inf/NaN 原样返回
+pub fn wrap_angle<T: NumericType>(angle: T) -> T {
+    let pi = T::pi();
+    let two_pi = pi + pi;
+    // 仅 inf/NaN 满足 x·0 ≠ 0
+    if angle * T::zero() != T::zero() {
+        return angle;
+    }
+    let mut a = angle;
+    // 超大输入的周期数受舍入影响，每轮把残差降到上一轮的舍入量级，f64 全范围也只需几十轮
+    while a > pi || a <= -pi {
+        let turns = (a / two_pi + T::from_f32(0.5)).floor();
+        let reduced = a - two_pi * turns;
+        // 恰在 −π 边界或舍入后未变化时补一个周期
+        a = if reduced != a {
+            reduced
+        } else if a > pi {
+            a - two_pi
+        } else {
+            a + two_pi
+        };
+    }
+    a
+}
+
+/// 仅用加速度计计算 pitch/roll（静态欧拉角，弧度）
+///
+/// 静止时加速度计读数为 `(sinθ, sinφ·cosθ, cosφ·cosθ)·g`，抬头时 x 轴读数为正
+pub fn pitch_roll_from_accel<T: NumericType>(accel: (T, T, T)) -> (T, T) {
+    let (ax, ay, az) = accel;
+    let pitch = T::atan2(ax, (ay * ay + az * az).sqrt());
+    let roll = T::atan2(ay, az);
+    (pitch, roll)
+}
+
+/// 机体角速度 `(p, q, r)` 转换为欧拉角速率 `(θ̇, φ̇, ψ̇)`（rad/s）
+///
+/// - φ̇ = p − (q·sinφ + r·cosφ)·tanθ
+/// - θ̇ = r·sinφ − q·cosφ
+/// - ψ̇ = (q·sinφ + r·cosφ) / cosθ
+///
+/// θ 为抬头为正的俯仰角，与绕 y 轴右手旋转角符号相反，故 θ̇ 与 tanθ 项取反
+///
+/// 奇异点（θ = ±90°）附近 |cosθ| 限制为不小于 1e-3
+pub fn euler_rates<T: NumericType>(angles: (T, T, T), gyro: (T, T, T)) -> (T, T, T) {
+    let (pitch, roll, _) = angles;
+    let (p, q, r) = gyro;
+    let (sin_roll, cos_roll) = (roll.sin(), roll.cos());
+    let min_cos = T::from_f32(MIN_COS_PITCH);
+    let mut cos_pitch = pitch.cos();
+    if cos_pitch.abs() < min_cos {
+        cos_pitch = if cos_pitch < T::zero() {
+            -min_cos
+        } else {
+            min_cos
+        };
+    }
+    let coupled = q * sin_roll + r * cos_roll;
+    (
+        r * sin_roll - q * cos_roll,
+        p - coupled * pitch.sin() / cos_pitch,
+        coupled / cos_pitch,
+    )
+}
+
+/// 陀螺仪角速度积分（弧度）
+///
+/// 机体角速度经 [`euler_rates`] 转换后积分，返回 `(pitch, roll, yaw)`，
+/// roll 与 yaw 包裹到 ±π
+pub fn integrate_gyro<T: NumericType>(angles: (T, T, T), gyro: (T, T, T), dt: T) -> (T, T, T) {
+    let (pitch, roll, yaw) = angles;
+    let (pitch_rate, roll_rate, yaw_rate) = euler_rates(angles, gyro);
+    (
+        pitch + pitch_rate * dt,
+        wrap_angle(roll + roll_rate * dt),
+        wrap_angle(yaw + yaw_rate * dt),
+    )
+}
+
+/// 互补滤波融合加速度计角度和陀螺仪积分角度（弧度）
+///
+/// 在角度差上做加权，±π 附近不会因跳变而错误平均。
+/// 返回融合后的 `(pitch, roll, yaw)`，yaw 仅来自陀螺仪
+pub fn complementary_filter<T: NumericType>(
+    acc_pitch: T,
+    acc_roll: T,
+    gyro_angles: (T, T, T),
+    alpha: T,
+) -> (T, T, T) {
+    let (pitch_g, roll_g, yaw_g) = gyro_angles;
+    (
+        acc_pitch + alpha * (pitch_g - acc_pitch),
+        wrap_angle(acc_roll + alpha * wrap_angle(roll_g - acc_roll)),
+        yaw_g,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accel_angles_match_quaternion_convention() {
+        let (pitch, roll) = (0.2f32, -0.4f32);
+        let q = Quaternion::from_euler(pitch, roll, 0.0);
+        // 世界系重力反作用 (0,0,1) 转到机体系
+        let g = q.conjugate().rotate_vector((0.0, 0.0, 1.0));
+        let (p, r) = pitch_roll_from_accel(g);
+        assert!((p - pitch).abs() < 1e-5);
+        assert!((r - roll).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_integrate_gyro_body_rates() {
+        // roll 90° 时机体 y 轴角速度对应 yaw 变化
+        let angles = (0.0f32, core::f32::consts::FRAC_PI_2, 0.0);
+        let (pitch, roll, yaw) = integrate_gyro(angles, (0.0, 1.0, 0.0), 0.1);
+        assert!(pitch.abs() < 1e-5);
+        assert!((roll - core::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        assert!((yaw - 0.1).abs() < 1e-5);
+
+        // 水平时 gx → roll，gy → pitch（绕 +y 旋转使机头向下）
+        let (pitch, roll, _) = integrate_gyro((0.0f32, 0.0, 0.0), (0.5, 0.2, 0.0), 0.1);
+        assert!((roll - 0.05).abs() < 1e-6);
+        assert!((pitch + 0.02).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_integrate_gyro_matches_quaternion_kinematics() {
+        let angles = (0.3f32, -0.5, 1.0);
+        let gyro = (0.2f32, -0.3, 0.4);
+        let dt = 1e-3;
+        let (pitch, roll, yaw) = integrate_gyro(angles, gyro, dt);
+
+        // 机体系角速度右乘：q' = q ⊗ [1, ω·dt/2]
+        let step = Quaternion::new(1.0, gyro.0 * dt / 2.0, gyro.1 * dt / 2.0, gyro.2 * dt / 2.0);
+        let q = (Quaternion::from_euler(angles.0, angles.1, angles.2) * step).normalize();
+        let (qp, qr, qy) = q.to_euler();
+        assert!((pitch - qp).abs() < 1e-5);
+        assert!((roll - qr).abs() < 1e-5);
+        assert!((yaw - qy).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_nose_up_pitch_is_positive() {
+        // 抬头 0.3 rad：机体 x 轴指向斜上方，加速度计 x 轴读数为正
+        let q = Quaternion::from_euler(0.3f32, 0.0, 0.0);
+        let (_, _, nose_z) = q.rotate_vector((1.0, 0.0, 0.0));
+        assert!(nose_z > 0.0);
+        let (pitch, roll) = pitch_roll_from_accel((libm::sinf(0.3), 0.0, libm::cosf(0.3)));
+        assert!((pitch - 0.3).abs() < 1e-6 && roll.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_wrap_angle_bounded() {
+        use core::f32::consts::PI;
+
+        assert!((wrap_angle(7.0f32) - (7.0 - 2.0 * PI)).abs() < 1e-6);
+        assert_eq!(wrap_angle(-PI), PI);
+        assert_eq!(wrap_angle(f32::INFINITY), f32::INFINITY);
+        assert!(wrap_angle(f32::NAN).is_nan());
+
+        for angle in [1e9f32, -1e9, f32::MAX, -f32::MAX] {
+            let a = wrap_angle(angle);
+            assert!(a > -PI && a <= PI);
+        }
+        // f64 精度足够时结果与精确取模一致
+        let a = wrap_angle(1e9f64);
+        let expected = libm::remainder(1e9, 2.0 * core::f64::consts::PI);
+        assert!((a - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_yaw_wraps() {
+        let (_, _, yaw) = integrate_gyro((0.0f32, 0.0, 3.1), (0.0, 0.0, 1.0), 0.1);
+        assert!((yaw - (3.2 - 2.0 * core::f32::consts::PI)).abs() < 1e-5);
+        let (_, roll, _) = complementary_filter(0.0, -3.1f32, (0.0, 3.1, 0.0), 0.5);
+        assert!((roll.abs() - core::f32::consts::PI).abs() < 1e-5);
+    }
+}
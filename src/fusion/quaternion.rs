@@ -0,0 +1,173 @@
+//! 姿态四元数
+
+use crate::numeric::NumericType;
+
+/// 单位四元数，表示机体系到世界系的旋转
+///
+/// 欧拉角采用 ZYX 顺序（先 yaw 绕Z，再 pitch 绕Y，最后 roll 绕X），
+/// pitch 抬头为正，即绕 Y 轴右手旋转角的相反数（见 [`crate::fusion`]），
+/// 接口中的欧拉角统一按 `(pitch, roll, yaw)` 顺序，单位弧度
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T: NumericType> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: NumericType> Default for Quaternion<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: NumericType> Quaternion<T> {
+    /// 创建四元数
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// 单位四元数（无旋转）
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    /// 由欧拉角构造（弧度）
+    pub fn from_euler(pitch: T, roll: T, yaw: T) -> Self {
+        let half = T::from_f32(0.5);
+        // 抬头为正的 pitch 对应绕 Y 轴的负向旋转
+        let theta = -pitch;
+        let (sr, cr) = ((roll * half).sin(), (roll * half).cos());
+        let (sp, cp) = ((theta * half).sin(), (theta * half).cos());
+        let (sy, cy) = ((yaw * half).sin(), (yaw * half).cos());
+        Self::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        )
+    }
+
+    /// 由静止时的加速度计读数（重力反作用方向）构造姿态，yaw 置零
+    ///
+    /// 读数为零向量时返回 `None`
+    pub fn from_gravity(accel: (T, T, T)) -> Option<Self> {
+        let (ax, ay, az) = accel;
+        if ax == T::zero() && ay == T::zero() && az == T::zero() {
+            return None;
+        }
+        let (pitch, roll) = super::pitch_roll_from_accel(accel);
+        Some(Self::from_euler(pitch, roll, T::zero()))
+    }
+
+    /// 模长
+    pub fn norm(&self) -> T {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// 归一化，模长为0时返回单位四元数
+    pub fn normalize(&self) -> Self {
+        let n = self.norm();
+        if n == T::zero() {
+            return Self::identity();
+        }
+        Self::new(self.w / n, self.x / n, self.y / n, self.z / n)
+    }
+
+    /// 共轭（单位四元数的逆）
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// 转换为欧拉角 `(pitch, roll, yaw)`（弧度）
+    pub fn to_euler(&self) -> (T, T, T) {
+        let one = T::one();
+        let two = T::from_f32(2.0);
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+
+        let roll = T::atan2(two * (w * x + y * z), one - two * (x * x + y * y));
+        // 绕 Y 轴右手旋转角的正弦为 2(wy − zx)，抬头为正的 pitch 取其相反数
+        let mut sin_pitch = two * (z * x - w * y);
+        if sin_pitch > one {
+            sin_pitch = one;
+        } else if sin_pitch < -one {
+            sin_pitch = -one;
+        }
+        // asin(s) = atan2(s, sqrt(1 - s²))
+        let pitch = T::atan2(sin_pitch, (one - sin_pitch * sin_pitch).sqrt());
+        let yaw = T::atan2(two * (w * z + x * y), one - two * (y * y + z * z));
+        (pitch, roll, yaw)
+    }
+
+    /// 转换为旋转矩阵（机体系 → 世界系）
+    pub fn to_rotation_matrix(&self) -> [[T; 3]; 3] {
+        let one = T::one();
+        let two = T::from_f32(2.0);
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    /// 将机体系向量旋转到世界系
+    pub fn rotate_vector(&self, v: (T, T, T)) -> (T, T, T) {
+        let m = self.to_rotation_matrix();
+        (
+            m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+            m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+            m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+        )
+    }
+}
+
+impl<T: NumericType> core::ops::Mul for Quaternion<T> {
+    type Output = Self;
+
+    /// Hamilton 乘积
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euler_roundtrip() {
+        let q = Quaternion::<f32>::from_euler(0.3, -0.5, 1.2);
+        let (pitch, roll, yaw) = q.to_euler();
+        assert!((pitch - 0.3).abs() < 1e-5);
+        assert!((roll + 0.5).abs() < 1e-5);
+        assert!((yaw - 1.2).abs() < 1e-5);
+        assert!((q.norm() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rotate_vector_matches_yaw() {
+        let q = Quaternion::<f32>::from_euler(0.0, 0.0, core::f32::consts::FRAC_PI_2);
+        let (x, y, z) = q.rotate_vector((1.0, 0.0, 0.0));
+        assert!(x.abs() < 1e-6 && (y - 1.0).abs() < 1e-6 && z.abs() < 1e-6);
+        let inv = q * q.conjugate();
+        assert!((inv.w - 1.0).abs() < 1e-6);
+    }
+}
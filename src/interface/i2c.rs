@@ -0,0 +1,45 @@
+use super::Interface;
+use crate::error::Result;
+
+use embedded_hal_async::i2c::I2c;
+
+/// I2C 从机地址（由 AD0 引脚电平决定）
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum I2cAddress {
+    /// AD0 接低电平
+    #[default]
+    Ad0Low = 0x68,
+    /// AD0 接高电平
+    Ad0High = 0x69,
+}
+
+/// 基于 `I2c` 的接口
+pub struct I2cInterface<I2C> {
+    i2c: I2C,
+    address: u8,
+}
+
+impl<I2C> I2cInterface<I2C> {
+    /// 创建I2C接口
+    pub fn new(i2c: I2C, address: I2cAddress) -> Self {
+        Self {
+            i2c,
+            address: address as u8,
+        }
+    }
+}
+
+impl<I2C> Interface for I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    type Error = I2C::Error;
+
+    async fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        Ok(self.i2c.write_read(self.address, &[reg], buf).await?)
+    }
+
+    async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+        Ok(self.i2c.write(self.address, &[reg, val]).await?)
+    }
+}
@@ -0,0 +1,194 @@
+//! 总线接口抽象
+//!
+//! 将寄存器读写与具体总线（SPI/I2C）解耦，驱动逻辑只依赖 [`Interface`]。
+//! 新的总线或测试替身只需实现该trait即可接入 [`Mpu6050`](crate::Mpu6050)。
+
+mod i2c;
+mod spi;
+
+pub use i2c::{I2cAddress, I2cInterface};
+pub use spi::{SpiDeviceInterface, SpiInterface};
+
+use crate::error::Result;
+
+/// 寄存器访问接口
+///
+/// 实现者负责总线层面的帧格式（如SPI读标志位、I2C从机地址），
+/// 寄存器地址始终为芯片寄存器表中的原始地址。总线错误以
+/// [`Mpu6050Error::Bus`](crate::Mpu6050Error::Bus) 返回，片选等其他故障使用对应的错误变体。
+#[allow(async_fn_in_trait)]
+pub trait Interface {
+    /// 总线错误类型
+    type Error;
+
+    /// 从 `reg` 开始连续读取 `buf.len()` 个字节（突发读取）
+    ///
+    /// 芯片在突发读取时自动递增寄存器地址（FIFO_R_W 除外）。
+    async fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// 写入单个寄存器
+    async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error>;
+
+    /// 读取单个寄存器
+    async fn read_register(&mut self, reg: u8) -> Result<u8, Self::Error> {
+        let mut buf = [0u8; 1];
+        self.read_registers(reg, &mut buf).await?;
+        Ok(buf[0])
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use super::Interface;
+    use crate::error::Result;
+    use crate::register::{FIFO_COUNTH, FIFO_R_W, USER_CTRL, USER_CTRL_FIFO_RESET};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// 以数组模拟寄存器表的测试替身
+    ///
+    /// 读 FIFO_R_W 时从 `fifo` 队列依次弹出，FIFO_COUNTH/L 返回队列剩余字节数，
+    /// 写 USER_CTRL 的 FIFO_RESET 位清空队列（该位与硬件一样自动清零）。
+    /// `read_count` 记录读事务次数，`last_read` 为最近一次读取的 `(起始寄存器, 字节数)`
+    pub struct MockInterface {
+        pub regs: [u8; 128],
+        pub fifo: [u8; 256],
+        pub fifo_len: usize,
+        fifo_pos: usize,
+        pub read_count: usize,
+        pub last_read: Option<(u8, usize)>,
+    }
+
+    impl MockInterface {
+        pub fn new() -> Self {
+            Self {
+                regs: [0; 128],
+                fifo: [0; 256],
+                fifo_len: 0,
+                fifo_pos: 0,
+                read_count: 0,
+                last_read: None,
+            }
+        }
+
+        /// 向模拟 FIFO 追加数据
+        pub fn push_fifo(&mut self, data: &[u8]) {
+            self.fifo[self.fifo_len..self.fifo_len + data.len()].copy_from_slice(data);
+            self.fifo_len += data.len();
+        }
+    }
+
+    impl Interface for MockInterface {
+        type Error = ();
+
+        async fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+            self.read_count += 1;
+            self.last_read = Some((reg, buf.len()));
+            match reg {
+                FIFO_R_W => {
+                    for byte in buf.iter_mut() {
+                        *byte = self.fifo[self.fifo_pos];
+                        self.fifo_pos += 1;
+                    }
+                    return Ok(());
+                }
+                FIFO_COUNTH => {
+                    let count = (self.fifo_len - self.fifo_pos) as u16;
+                    buf.copy_from_slice(&count.to_be_bytes()[..buf.len()]);
+                    return Ok(());
+                }
+                _ => {}
+            }
+            let start = reg as usize;
+            buf.copy_from_slice(&self.regs[start..start + buf.len()]);
+            Ok(())
+        }
+
+        async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+            let mut val = val;
+            if reg == USER_CTRL && val & USER_CTRL_FIFO_RESET != 0 {
+                self.fifo_len = 0;
+                self.fifo_pos = 0;
+                val &= !USER_CTRL_FIFO_RESET;
+            }
+            self.regs[reg as usize] = val;
+            Ok(())
+        }
+    }
+
+    /// 阻塞驱动复用同一寄存器表
+    impl crate::blocking::Interface for MockInterface {
+        type Error = ();
+
+        fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+            block_on(Interface::read_registers(self, reg, buf))
+        }
+
+        fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+            block_on(Interface::write_register(self, reg, val))
+        }
+    }
+
+    /// 每次事务都失败的阻塞总线，错误值为事务序号（从0开始）
+    #[derive(Default)]
+    pub struct FailingInterface {
+        transactions: u32,
+    }
+
+    impl FailingInterface {
+        fn fail(&mut self) -> Result<(), u32> {
+            let n = self.transactions;
+            self.transactions += 1;
+            Err(n.into())
+        }
+    }
+
+    impl crate::blocking::Interface for FailingInterface {
+        type Error = u32;
+
+        fn read_registers(&mut self, _reg: u8, _buf: &mut [u8]) -> Result<(), u32> {
+            self.fail()
+        }
+
+        fn write_register(&mut self, _reg: u8, _val: u8) -> Result<(), u32> {
+            self.fail()
+        }
+    }
+
+    /// 不等待的延时提供者
+    pub struct NoDelay;
+
+    impl embedded_hal::delay::DelayNs for NoDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    /// 轮询一个不会挂起的future直到完成
+    pub fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::{MockInterface, block_on};
+    use crate::Mpu6050;
+    use crate::config::Mpu6050Config;
+    use crate::register::*;
+
+    #[test]
+    fn test_write_register_goes_through_interface() {
+        let mut mpu: Mpu6050<_, f32> =
+            Mpu6050::with_interface(MockInterface::new(), Mpu6050Config::default());
+
+        block_on(mpu.write_register(SMPLRT_DIV, 9)).unwrap();
+        assert_eq!(block_on(mpu.read_register(SMPLRT_DIV)).unwrap(), 9);
+        assert_eq!(mpu.release().regs[SMPLRT_DIV as usize], 9);
+    }
+}
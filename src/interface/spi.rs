@@ -0,0 +1,95 @@
+use super::Interface;
+use crate::error::{Mpu6050Error, Result};
+
+use embedded_hal::digital::OutputPin;
+use embedded_hal_async::spi::{Operation, SpiBus, SpiDevice};
+
+/// SPI 读标志位
+const SPI_READ: u8 = 0x80;
+
+/// 基于独占 `SpiBus` + 片选引脚的接口
+pub struct SpiInterface<SPI, CS> {
+    spi: SPI,
+    cs: CS,
+}
+
+impl<SPI, CS> SpiInterface<SPI, CS> {
+    /// 创建SPI接口
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        Self { spi, cs }
+    }
+}
+
+impl<SPI, CS> SpiInterface<SPI, CS>
+where
+    SPI: SpiBus<u8>,
+    CS: OutputPin,
+{
+    /// 释放片选，传输错误优先于片选错误返回
+    fn deselect(&mut self, res: Result<(), SPI::Error>) -> Result<(), SPI::Error> {
+        let cs = self.cs.set_high().map_err(|_| Mpu6050Error::ChipSelect);
+        res.and(cs)
+    }
+}
+
+impl<SPI, CS> Interface for SpiInterface<SPI, CS>
+where
+    SPI: SpiBus<u8>,
+    CS: OutputPin,
+{
+    type Error = SPI::Error;
+
+    async fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        self.cs.set_low().map_err(|_| Mpu6050Error::ChipSelect)?;
+        let res = async {
+            self.spi.write(&[reg | SPI_READ]).await?;
+            self.spi.read(buf).await?;
+            Ok(self.spi.flush().await?)
+        }
+        .await;
+        self.deselect(res)
+    }
+
+    async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+        self.cs.set_low().map_err(|_| Mpu6050Error::ChipSelect)?;
+        let res = async {
+            self.spi.write(&[reg & !SPI_READ, val]).await?;
+            Ok(self.spi.flush().await?)
+        }
+        .await;
+        self.deselect(res)
+    }
+}
+
+/// 基于 `SpiDevice` 的接口
+///
+/// 片选与总线锁由 `SpiDevice` 实现（如 embassy-embedded-hal 的共享总线）管理，
+/// 可与其他外设共用同一条SPI总线
+pub struct SpiDeviceInterface<SPI> {
+    spi: SPI,
+}
+
+impl<SPI> SpiDeviceInterface<SPI> {
+    /// 创建SPI设备接口
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+}
+
+impl<SPI> Interface for SpiDeviceInterface<SPI>
+where
+    SPI: SpiDevice<u8>,
+{
+    type Error = SPI::Error;
+
+    async fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        Ok(self
+            .spi
+            .transaction(&mut [Operation::Write(&[reg | SPI_READ]), Operation::Read(buf)])
+            .await?)
+    }
+
+    async fn write_register(&mut self, reg: u8, val: u8) -> Result<(), Self::Error> {
+        Ok(self.spi.write(&[reg & !SPI_READ, val]).await?)
+    }
+}
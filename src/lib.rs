@@ -1,15 +1,23 @@
 #![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_main)]
 
+pub mod blocking;
+pub mod calibration;
 pub mod config;
 pub mod driver;
 pub mod error;
+pub mod fusion;
+pub mod interface;
 pub mod numeric;
 pub mod register;
 pub mod util;
 
 pub use crate::config::Mpu6050Config;
+pub use crate::driver::ChipVariant;
+use crate::interface::{I2cAddress, I2cInterface, SpiDeviceInterface, SpiInterface};
+
 pub use crate::error::{DeviceStatus, Mpu6050Error, Result, SensorStatus, SensorType};
+pub use crate::interface::Interface;
 pub use crate::numeric::{FixedI8F24, FixedI16F16, NumericConverter, NumericType};
 
 pub const DEG2RAD: f32 = core::f32::consts::PI / 180.0;
@@ -17,7 +25,7 @@ pub const RAD2DEG: f32 = 180.0 / core::f32::consts::PI;
 
 /// MPU6050 数据快照结构体（泛型版本）
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct SensorData<T: NumericType> {
     pub accel: (T, T, T),
     pub gyro: (T, T, T),
@@ -34,48 +42,54 @@ impl<T: NumericType> Default for SensorData<T> {
     }
 }
 
+/// MPU6050 原始数据快照（单位：原始ADC）
+///
+/// 由一次突发读取得到，三类数据属于同一采样周期
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RawSensorData {
+    pub accel: (i16, i16, i16),
+    pub gyro: (i16, i16, i16),
+    pub temp: i16,
+}
+
 /// 向后兼容的f32版本
 pub type SensorDataF32 = SensorData<f32>;
 
 /// 向后兼容的f32版本MPU6050
-pub type Mpu6050F32<SPI, CS> = Mpu6050<SPI, CS, f32>;
+pub type Mpu6050F32<DI> = Mpu6050<DI, f32>;
 
 /// 向后兼容的f32版本Builder
-pub type Mpu6050BuilderF32<SPI, CS> = Mpu6050Builder<SPI, CS, f32>;
+pub type Mpu6050BuilderF32<DI> = Mpu6050Builder<DI, f32>;
 
 /// MPU6050 链式构建器
-pub struct Mpu6050Builder<SPI, CS, T: NumericType = f32> {
-    spi: Option<SPI>,
-    cs: Option<CS>,
+pub struct Mpu6050Builder<DI, T: NumericType = f32> {
+    interface: Option<DI>,
     config: Mpu6050Config,
     accel_offset: (i16, i16, i16),
     gyro_offset: (i16, i16, i16),
+    profile: Option<calibration::CalibrationProfile>,
     initial_attitude: (T, T, T),
 }
 
-impl<SPI, CS, T: NumericType> Default for Mpu6050Builder<SPI, CS, T> {
+impl<DI, T: NumericType> Default for Mpu6050Builder<DI, T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<SPI, CS, T: NumericType> Mpu6050Builder<SPI, CS, T> {
+impl<DI, T: NumericType> Mpu6050Builder<DI, T> {
     pub fn new() -> Self {
         Self {
-            spi: None,
-            cs: None,
+            interface: None,
             config: Mpu6050Config::default(),
             accel_offset: (0, 0, 0),
             gyro_offset: (0, 0, 0),
+            profile: None,
             initial_attitude: (T::zero(), T::zero(), T::zero()),
         }
     }
-    pub fn spi(mut self, spi: SPI) -> Self {
-        self.spi = Some(spi);
-        self
-    }
-    pub fn cs(mut self, cs: CS) -> Self {
-        self.cs = Some(cs);
+    pub fn interface(mut self, interface: DI) -> Self {
+        self.interface = Some(interface);
         self
     }
     pub fn config(mut self, config: Mpu6050Config) -> Self {
@@ -90,18 +104,35 @@ impl<SPI, CS, T: NumericType> Mpu6050Builder<SPI, CS, T> {
         self.gyro_offset = offset;
         self
     }
+    /// 应用持久化的标定档案，覆盖 `accel_offset`/`gyro_offset`，偏移按配置量程换算
+    pub fn calibration_profile(mut self, profile: calibration::CalibrationProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
     pub fn initial_attitude(mut self, pitch: T, roll: T, yaw: T) -> Self {
         self.initial_attitude = (pitch, roll, yaw);
         self
     }
-    pub fn build(self) -> Mpu6050<SPI, CS, T> {
+    pub fn build(self) -> Mpu6050<DI, T> {
+        let (accel_offset, gyro_offset) = match &self.profile {
+            Some(profile) => profile.offsets_for(self.config.accel_scale, self.config.gyro_scale),
+            None => (self.accel_offset, self.gyro_offset),
+        };
         Mpu6050 {
-            spi: self.spi.expect("SPI未设置"),
-            cs: self.cs.expect("CS未设置"),
+            interface: self.interface.expect("总线接口未设置"),
             config: self.config,
-            accel_offset: self.accel_offset,
-            gyro_offset: self.gyro_offset,
-            // last_update: None,
+            chip: ChipVariant::default(),
+            accel_offset,
+            gyro_offset,
+            accel_calibration: self
+                .profile
+                .map_or(calibration::AccelCalibration::identity(), |p| {
+                    p.accel_calibration
+                }),
+            gyro_temp_model: self.profile.and_then(|p| p.gyro_temp_model),
+            last_update: None,
+            fifo_pending: driver::FifoPending::default(),
+            fifo_status: driver::FifoStatus::default(),
             pitch: self.initial_attitude.0,
             roll: self.initial_attitude.1,
             yaw: self.initial_attitude.2,
@@ -109,14 +140,38 @@ impl<SPI, CS, T: NumericType> Mpu6050Builder<SPI, CS, T> {
     }
 }
 
+impl<SPI, CS, T: NumericType> Mpu6050Builder<SpiInterface<SPI, CS>, T> {
+    pub fn spi(self, spi: SPI, cs: CS) -> Self {
+        self.interface(SpiInterface::new(spi, cs))
+    }
+}
+
+impl<SPI, T: NumericType> Mpu6050Builder<SpiDeviceInterface<SPI>, T> {
+    pub fn spi_device(self, spi: SPI) -> Self {
+        self.interface(SpiDeviceInterface::new(spi))
+    }
+}
+
+impl<I2C, T: NumericType> Mpu6050Builder<I2cInterface<I2C>, T> {
+    pub fn i2c(self, i2c: I2C, address: I2cAddress) -> Self {
+        self.interface(I2cInterface::new(i2c, address))
+    }
+}
+
 /// MPU6050 主结构体
-pub struct Mpu6050<SPI, CS, T: NumericType = f32> {
-    pub(crate) spi: SPI,
-    pub(crate) cs: CS,
+///
+/// `DI` 为总线接口，见 [`interface`] 模块
+pub struct Mpu6050<DI, T: NumericType = f32> {
+    pub(crate) interface: DI,
     pub(crate) config: Mpu6050Config,
+    pub(crate) chip: ChipVariant,
     pub(crate) accel_offset: (i16, i16, i16),
     pub(crate) gyro_offset: (i16, i16, i16),
-    // pub(crate) last_update: Option<u64>,
+    pub(crate) accel_calibration: calibration::AccelCalibration,
+    pub(crate) gyro_temp_model: Option<calibration::GyroTempModel>,
+    pub(crate) last_update: Option<embassy_time::Instant>,
+    pub(crate) fifo_pending: driver::FifoPending,
+    pub(crate) fifo_status: driver::FifoStatus,
     pub pitch: T,
     pub roll: T,
     pub yaw: T,
@@ -51,6 +51,21 @@ pub trait NumericType:
     /// 常量：PI
     fn pi() -> Self;
 
+    /// 正弦
+    fn sin(self) -> Self {
+        Self::from_f32(libm::sinf(self.to_f32()))
+    }
+
+    /// 余弦
+    fn cos(self) -> Self {
+        Self::from_f32(libm::cosf(self.to_f32()))
+    }
+
+    /// 向下取整
+    fn floor(self) -> Self {
+        Self::from_f32(libm::floorf(self.to_f32()))
+    }
+
     /// 常量：角度到弧度转换因子
     fn deg_to_rad() -> Self {
         Self::pi() / Self::from_f32(180.0)
@@ -104,6 +119,16 @@ impl NumericType for f32 {
         libm::powf(self, exp)
     }
 
+    #[inline]
+    fn sin(self) -> Self {
+        libm::sinf(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        libm::cosf(self)
+    }
+
     #[inline]
     fn abs(self) -> Self {
         libm::fabsf(self)
@@ -113,6 +138,11 @@ impl NumericType for f32 {
     fn pi() -> Self {
         core::f32::consts::PI
     }
+
+    #[inline]
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
 }
 
 /// f64的NumericType实现
@@ -157,6 +187,16 @@ impl NumericType for f64 {
         libm::pow(self, exp)
     }
 
+    #[inline]
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        libm::cos(self)
+    }
+
     #[inline]
     fn abs(self) -> Self {
         libm::fabs(self)
@@ -166,6 +206,11 @@ impl NumericType for f64 {
     fn pi() -> Self {
         core::f64::consts::PI
     }
+
+    #[inline]
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
 }
 
 // 定点数类型别名
@@ -260,9 +305,9 @@ impl NumericConverter {
         scale_factor: f32,
         offset: (i16, i16, i16),
     ) -> (T, T, T) {
-        let x = T::from_f32((raw.0 - offset.0) as f32 / scale_factor);
-        let y = T::from_f32((raw.1 - offset.1) as f32 / scale_factor);
-        let z = T::from_f32((raw.2 - offset.2) as f32 / scale_factor);
+        let x = T::from_f32((raw.0 as i32 - offset.0 as i32) as f32 / scale_factor);
+        let y = T::from_f32((raw.1 as i32 - offset.1 as i32) as f32 / scale_factor);
+        let z = T::from_f32((raw.2 as i32 - offset.2 as i32) as f32 / scale_factor);
         (x, y, z)
     }
 
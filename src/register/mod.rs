@@ -7,14 +7,22 @@ const ALPHA: f32 = 0.98;
 // ===== 设备识别相关寄存器 =====
 /// 设备ID寄存器地址
 pub const WHO_AM_I: u8 = 0x75;
-/// 设备ID期望值
+/// 设备ID期望值（MPU6050/MPU6000）
 pub const WHO_AM_I_VALUE: u8 = 0x68;
+/// MPU6500 设备ID
+pub const WHO_AM_I_MPU6500: u8 = 0x70;
+/// MPU9250 设备ID
+pub const WHO_AM_I_MPU9250: u8 = 0x71;
+/// ICM-20602 设备ID
+pub const WHO_AM_I_ICM20602: u8 = 0x12;
 
 // ===== 电源管理相关寄存器 =====
 /// 电源管理1寄存器
 pub const PWR_MGMT_1: u8 = 0x6B;
 /// 电源管理2寄存器
 pub const PWR_MGMT_2: u8 = 0x6C;
+/// PWR_MGMT_1 设备复位位，复位完成后自动清零
+pub const DEVICE_RESET: u8 = 0x80;
 
 // ===== 配置相关寄存器 =====
 /// 配置寄存器
@@ -23,8 +31,8 @@ pub const CONFIG: u8 = 0x1A;
 pub const GYRO_CONFIG: u8 = 0x1B;
 /// 加速度计配置寄存器
 pub const ACCEL_CONFIG: u8 = 0x1C;
-/// 加速度计配置2寄存器
-// pub const ACCEL_CONFIG2: u8 = 0x1D;
+/// 加速度计配置2寄存器（仅MPU6500系列）
+pub const ACCEL_CONFIG2: u8 = 0x1D;
 
 // ===== 采样率相关寄存器 =====
 /// 采样率分频寄存器
@@ -35,6 +43,8 @@ pub const SMPLRT_DIV: u8 = 0x19;
 pub const INT_ENABLE: u8 = 0x38;
 /// 中断状态寄存器
 pub const INT_STATUS: u8 = 0x3A;
+/// INT_STATUS FIFO 溢出标志位（读取 INT_STATUS 后清零）
+pub const INT_STATUS_FIFO_OFLOW: u8 = 0x10;
 
 // ===== 数据输出相关寄存器 =====
 /// 加速度计X高字节
@@ -69,6 +79,10 @@ pub const GYRO_ZOUT_L: u8 = 0x48;
 // ===== 用户控制相关寄存器 =====
 /// 用户控制寄存器
 pub const USER_CTRL: u8 = 0x6A;
+/// USER_CTRL FIFO 使能位
+pub const USER_CTRL_FIFO_EN: u8 = 0x40;
+/// USER_CTRL FIFO 复位位，复位完成后自动清零
+pub const USER_CTRL_FIFO_RESET: u8 = 0x04;
 
 // ===== FIFO相关寄存器 =====
 /// FIFO使能寄存器
@@ -81,6 +95,12 @@ pub const FIFO_COUNTL: u8 = 0x73;
 pub const FIFO_R_W: u8 = 0x74;
 
 // ===== 自检相关寄存器 =====
+// MPU6050 的 0x0D~0x0F 同时包含加速度计（高3位）和陀螺仪（低5位）出厂值，
+// 加速度计低2位位于 SELF_TEST_A；MPU6500 系列陀螺仪出厂值位于 0x00~0x02。
+/// 加速度计自检低位（MPU6050）
+pub const SELF_TEST_A: u8 = 0x10;
+/// GYRO_CONFIG/ACCEL_CONFIG 中 X/Y/Z 三轴自检使能位
+pub const SELF_TEST_ENABLE: u8 = 0xE0;
 /// X轴加速度计自检
 pub const SELF_TEST_X_ACCEL: u8 = 0x0D;
 /// Y轴加速度计自检
@@ -95,17 +115,43 @@ pub const SELF_TEST_Y_GYRO: u8 = 0x01;
 pub const SELF_TEST_Z_GYRO: u8 = 0x02;
 
 // ===== 偏移相关寄存器 =====
-/// X轴加速度计偏移高字节
+/// X轴加速度计偏移高字节（MPU6050/MPU6000）
+pub const XA_OFFS_USRH: u8 = 0x06;
+/// X轴加速度计偏移低字节（MPU6050/MPU6000）
+pub const XA_OFFS_USRL: u8 = 0x07;
+/// Y轴加速度计偏移高字节（MPU6050/MPU6000）
+pub const YA_OFFS_USRH: u8 = 0x08;
+/// Y轴加速度计偏移低字节（MPU6050/MPU6000）
+pub const YA_OFFS_USRL: u8 = 0x09;
+/// Z轴加速度计偏移高字节（MPU6050/MPU6000）
+pub const ZA_OFFS_USRH: u8 = 0x0A;
+/// Z轴加速度计偏移低字节（MPU6050/MPU6000）
+pub const ZA_OFFS_USRL: u8 = 0x0B;
+/// X轴陀螺仪偏移高字节
+pub const XG_OFFS_USRH: u8 = 0x13;
+/// X轴陀螺仪偏移低字节
+pub const XG_OFFS_USRL: u8 = 0x14;
+/// Y轴陀螺仪偏移高字节
+pub const YG_OFFS_USRH: u8 = 0x15;
+/// Y轴陀螺仪偏移低字节
+pub const YG_OFFS_USRL: u8 = 0x16;
+/// Z轴陀螺仪偏移高字节
+pub const ZG_OFFS_USRH: u8 = 0x17;
+/// Z轴陀螺仪偏移低字节
+pub const ZG_OFFS_USRL: u8 = 0x18;
+/// 加速度计偏移寄存器第0位：出厂温度补偿位，写入时必须保留
+pub const ACCEL_OFFSET_TEMP_COMP_BIT: i16 = 0x0001;
+/// X轴加速度计偏移高字节（MPU6500系列）
 pub const XA_OFFSET_H: u8 = 0x77;
-/// X轴加速度计偏移低字节
+/// X轴加速度计偏移低字节（MPU6500系列）
 pub const XA_OFFSET_L: u8 = 0x78;
-/// Y轴加速度计偏移高字节
+/// Y轴加速度计偏移高字节（MPU6500系列）
 pub const YA_OFFSET_H: u8 = 0x7A;
-/// Y轴加速度计偏移低字节
+/// Y轴加速度计偏移低字节（MPU6500系列）
 pub const YA_OFFSET_L: u8 = 0x7B;
-/// Z轴加速度计偏移高字节
+/// Z轴加速度计偏移高字节（MPU6500系列）
 pub const ZA_OFFSET_H: u8 = 0x7D;
-/// Z轴加速度计偏移低字节
+/// Z轴加速度计偏移低字节（MPU6500系列）
 pub const ZA_OFFSET_L: u8 = 0x7E;
 
 // ===== 物理常量定义 =====
@@ -127,7 +173,15 @@ pub const GYRO_SCALE_1000: f32 = 32.8;
 /// 陀螺仪量程常量（±2000°/s）
 pub const GYRO_SCALE_2000: f32 = 16.4;
 
-/// 温度转换比例
+/// 温度转换比例（MPU6500/MPU9250）
 pub const TEMP_SCALE: f32 = 333.87;
-/// 温度转换偏移
+/// 温度转换偏移（MPU6500/MPU9250）
 pub const TEMP_OFFSET: f32 = 21.0;
+/// 温度转换比例（MPU6050/MPU6000）
+pub const MPU6050_TEMP_SCALE: f32 = 340.0;
+/// 温度转换偏移（MPU6050/MPU6000）
+pub const MPU6050_TEMP_OFFSET: f32 = 36.53;
+/// 温度转换比例（ICM-20602）
+pub const ICM20602_TEMP_SCALE: f32 = 326.8;
+/// 温度转换偏移（ICM-20602）
+pub const ICM20602_TEMP_OFFSET: f32 = 25.0;
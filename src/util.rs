@@ -1,5 +1,7 @@
 pub use core::f32::consts::PI;
 
+use crate::numeric::NumericType;
+
 #[inline(always)]
 /// 角度转弧度
 pub fn deg2rad(deg: f32) -> f32 {
@@ -30,3 +32,53 @@ pub fn calc_dt(last: u64, now: u64, unit_per_sec: u64) -> f32 {
 pub fn calc_alpha(tau: f32, dt: f32) -> f32 {
     tau / (tau + dt)
 }
+
+/// 3×3 矩阵求逆（伴随矩阵法），行列式绝对值不超过 `min_det` 时视为奇异并返回 `None`
+pub(crate) fn inverse3<T: NumericType>(m: &[[T; 3]; 3], min_det: T) -> Option<[[T; 3]; 3]> {
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let det = m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02;
+    if det.abs() <= min_det {
+        return None;
+    }
+    Some([
+        [
+            c00 / det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) / det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) / det,
+        ],
+        [
+            c01 / det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) / det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) / det,
+        ],
+        [
+            c02 / det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) / det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) / det,
+        ],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse3() {
+        let m = [[2.0f32, 0.0, 1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 1.0]];
+        let inv = inverse3(&m, 0.0).unwrap();
+        assert_eq!(inv, [[1.0, 0.0, -1.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 2.0]]);
+
+        let singular = [[1.0f32, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]];
+        assert!(inverse3(&singular, 0.0).is_none());
+        assert!(
+            inverse3(
+                &[[1e-3f32, 0.0, 0.0], [0.0, 1e-3, 0.0], [0.0, 0.0, 1e-3]],
+                f32::EPSILON
+            )
+            .is_none()
+        );
+    }
+}